@@ -0,0 +1,166 @@
+//! Typed builders for calls to the entry points of an installed CEP-18 token.
+use casper_types::{bytesrepr::Bytes, runtime_args, Key, RuntimeArgs, U256};
+
+use crate::{
+    constants::{
+        AMOUNT, APPROVE_ENTRY_POINT_NAME, BURN_ENTRY_POINT_NAME, CHANGE_SECURITY_ENTRY_POINT_NAME,
+        DATA, DECREASE_ALLOWANCE_ENTRY_POINT_NAME, EXPIRES_AT, INCREASE_ALLOWANCE_ENTRY_POINT_NAME,
+        MINT_ENTRY_POINT_NAME, OWNER, RECIPIENT, SPENDER, TRANSFER_AND_CALL_ENTRY_POINT_NAME,
+        TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_AND_CALL_ENTRY_POINT_NAME,
+        TRANSFER_FROM_ENTRY_POINT_NAME,
+    },
+    Error, SecurityLists,
+};
+
+/// An entry point of the token along with the runtime arguments to call it with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntryPointCall {
+    pub entry_point: &'static str,
+    pub args: RuntimeArgs,
+}
+
+impl EntryPointCall {
+    fn new(entry_point: &'static str, args: RuntimeArgs) -> Self {
+        EntryPointCall { entry_point, args }
+    }
+}
+
+/// Transfers `amount` tokens from the caller to `recipient`.
+pub fn transfer(recipient: Key, amount: U256) -> EntryPointCall {
+    EntryPointCall::new(
+        TRANSFER_ENTRY_POINT_NAME,
+        runtime_args! {
+            RECIPIENT => recipient,
+            AMOUNT => amount,
+        },
+    )
+}
+
+/// Transfers `amount` tokens from `owner` to `recipient`, spending the allowance `owner` granted
+/// the caller.
+pub fn transfer_from(owner: Key, recipient: Key, amount: U256) -> EntryPointCall {
+    EntryPointCall::new(
+        TRANSFER_FROM_ENTRY_POINT_NAME,
+        runtime_args! {
+            OWNER => owner,
+            RECIPIENT => recipient,
+            AMOUNT => amount,
+        },
+    )
+}
+
+/// Transfers `amount` tokens from the caller to the contract package `recipient`, then calls its
+/// `on_cep18_received` entry point with `data`.
+pub fn transfer_and_call(recipient: Key, amount: U256, data: Bytes) -> EntryPointCall {
+    EntryPointCall::new(
+        TRANSFER_AND_CALL_ENTRY_POINT_NAME,
+        runtime_args! {
+            RECIPIENT => recipient,
+            AMOUNT => amount,
+            DATA => data,
+        },
+    )
+}
+
+/// Transfers `amount` tokens of `owner` to the contract package `recipient` through the allowance
+/// of the caller, then calls its `on_cep18_received` entry point with `data`.
+pub fn transfer_from_and_call(
+    owner: Key,
+    recipient: Key,
+    amount: U256,
+    data: Bytes,
+) -> EntryPointCall {
+    EntryPointCall::new(
+        TRANSFER_FROM_AND_CALL_ENTRY_POINT_NAME,
+        runtime_args! {
+            OWNER => owner,
+            RECIPIENT => recipient,
+            AMOUNT => amount,
+            DATA => data,
+        },
+    )
+}
+
+/// Lets `spender` spend `amount` tokens of the caller, until the `expires_at` block time if set.
+pub fn approve(
+    spender: Key,
+    amount: U256,
+    expires_at: Option<u64>,
+) -> Result<EntryPointCall, Error> {
+    let mut args = runtime_args! {
+        SPENDER => spender,
+        AMOUNT => amount,
+    };
+    if let Some(expires_at) = expires_at {
+        args.insert(EXPIRES_AT, expires_at)?;
+    }
+    Ok(EntryPointCall::new(APPROVE_ENTRY_POINT_NAME, args))
+}
+
+/// Raises the amount `spender` can spend on behalf of the caller by `amount`, moving its expiry to
+/// the `expires_at` block time if set.
+pub fn increase_allowance(
+    spender: Key,
+    amount: U256,
+    expires_at: Option<u64>,
+) -> Result<EntryPointCall, Error> {
+    let mut args = runtime_args! {
+        SPENDER => spender,
+        AMOUNT => amount,
+    };
+    if let Some(expires_at) = expires_at {
+        args.insert(EXPIRES_AT, expires_at)?;
+    }
+    Ok(EntryPointCall::new(
+        INCREASE_ALLOWANCE_ENTRY_POINT_NAME,
+        args,
+    ))
+}
+
+/// Lowers the amount `spender` can spend on behalf of the caller by `amount`.
+pub fn decrease_allowance(spender: Key, amount: U256) -> EntryPointCall {
+    EntryPointCall::new(
+        DECREASE_ALLOWANCE_ENTRY_POINT_NAME,
+        runtime_args! {
+            SPENDER => spender,
+            AMOUNT => amount,
+        },
+    )
+}
+
+/// Creates `amount` tokens for `owner`.
+pub fn mint(owner: Key, amount: U256) -> EntryPointCall {
+    EntryPointCall::new(
+        MINT_ENTRY_POINT_NAME,
+        runtime_args! {
+            OWNER => owner,
+            AMOUNT => amount,
+        },
+    )
+}
+
+/// Destroys `amount` tokens of `owner`.
+pub fn burn(owner: Key, amount: U256) -> EntryPointCall {
+    EntryPointCall::new(
+        BURN_ENTRY_POINT_NAME,
+        runtime_args! {
+            OWNER => owner,
+            AMOUNT => amount,
+        },
+    )
+}
+
+/// Gives the listed accounts their new security badge. At least one list has to be provided, but
+/// not the `admin_list`, Admins being added through the `propose_admin` and `accept_admin` entry
+/// points.
+pub fn change_security(security_lists: &SecurityLists) -> Result<EntryPointCall, Error> {
+    if security_lists.is_empty() {
+        return Err(Error::EmptySecurityChange);
+    }
+    if security_lists.admin_list.is_some() {
+        return Err(Error::AdminGrant);
+    }
+    let mut args = RuntimeArgs::new();
+    security_lists.insert_into(&mut args)?;
+    Ok(EntryPointCall::new(CHANGE_SECURITY_ENTRY_POINT_NAME, args))
+}
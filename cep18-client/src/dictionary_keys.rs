@@ -0,0 +1,24 @@
+//! Dictionary item keys under which the contract stores balances and allowances.
+use blake2::{
+    digest::{Update, VariableOutput},
+    VarBlake2b,
+};
+use casper_types::{bytesrepr::ToBytes, Key};
+
+/// Returns the item key of the balance of `owner` in the `balances` dictionary.
+pub fn balance_key(owner: &Key) -> String {
+    base64::encode(owner.to_bytes().expect("keys should serialize"))
+}
+
+/// Returns the item key of the amount `spender` can spend on behalf of `owner` in the
+/// `allowances` dictionary.
+pub fn allowance_key(owner: &Key, spender: &Key) -> String {
+    let mut preimage = owner.to_bytes().expect("keys should serialize");
+    preimage.append(&mut spender.to_bytes().expect("keys should serialize"));
+
+    let mut hasher = VarBlake2b::new(32).expect("32 is a valid blake2b output size");
+    hasher.update(preimage);
+    let mut key_bytes = Vec::with_capacity(32);
+    hasher.finalize_variable(|hash| key_bytes.extend_from_slice(hash));
+    hex::encode(key_bytes)
+}
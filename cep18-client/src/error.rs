@@ -0,0 +1,38 @@
+//! Error handling for the CEP-18 client.
+use core::fmt::{self, Display, Formatter};
+
+use casper_types::CLValueError;
+
+/// Errors that can occur while building runtime arguments.
+#[derive(Debug)]
+pub enum Error {
+    /// A runtime argument could not be serialized.
+    CLValue(CLValueError),
+    /// A `change_security` call was built without any account to change.
+    EmptySecurityChange,
+    /// A `change_security` call was built with an `admin_list`.
+    AdminGrant,
+}
+
+impl Display for Error {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            Error::CLValue(error) => write!(formatter, "invalid runtime argument: {}", error),
+            Error::EmptySecurityChange => {
+                write!(formatter, "change_security needs at least one account list")
+            }
+            Error::AdminGrant => write!(
+                formatter,
+                "change_security cannot add Admins, which go through propose_admin"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<CLValueError> for Error {
+    fn from(error: CLValueError) -> Self {
+        Error::CLValue(error)
+    }
+}
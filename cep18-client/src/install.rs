@@ -0,0 +1,118 @@
+//! Runtime arguments of the session code installing a CEP-18 token.
+use casper_types::{Key, RuntimeArgs, U256};
+use cep18_types::{EventsMode, TransferFilterMode};
+
+use crate::{
+    constants::{
+        ADMIN_LIST, BURNER_LIST, DECIMALS, ENABLE_MINT_BURN, EVENTS_MODE, KYC_ADMIN_LIST,
+        MAX_SUPPLY, MINTER_LIST, MINT_AND_BURN_LIST, NAME, NONE_LIST, PROPOSAL_THRESHOLD, SYMBOL,
+        TIMELOCK_DELAY, TOTAL_SUPPLY, TRANSFER_FILTER_MODE,
+    },
+    Error,
+};
+
+/// Accounts to give each security badge, as taken by install and `change_security`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SecurityLists {
+    pub admin_list: Option<Vec<Key>>,
+    pub minter_list: Option<Vec<Key>>,
+    pub burner_list: Option<Vec<Key>>,
+    pub mint_and_burn_list: Option<Vec<Key>>,
+    pub kyc_admin_list: Option<Vec<Key>>,
+    pub none_list: Option<Vec<Key>>,
+}
+
+impl SecurityLists {
+    /// Whether no list was provided.
+    pub fn is_empty(&self) -> bool {
+        self.lists().all(|(_, list)| list.is_none())
+    }
+
+    /// Inserts the provided lists into `args`.
+    pub(crate) fn insert_into(&self, args: &mut RuntimeArgs) -> Result<(), Error> {
+        for (name, list) in self.lists() {
+            if let Some(list) = list {
+                args.insert(name, list.clone())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn lists(&self) -> impl Iterator<Item = (&'static str, &Option<Vec<Key>>)> {
+        vec![
+            (ADMIN_LIST, &self.admin_list),
+            (MINTER_LIST, &self.minter_list),
+            (BURNER_LIST, &self.burner_list),
+            (MINT_AND_BURN_LIST, &self.mint_and_burn_list),
+            (KYC_ADMIN_LIST, &self.kyc_admin_list),
+            (NONE_LIST, &self.none_list),
+        ]
+        .into_iter()
+    }
+}
+
+/// Arguments of the session code installing a CEP-18 token. Optional arguments left to `None` use
+/// the contract defaults.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstallArgs {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub total_supply: U256,
+    pub events_mode: Option<EventsMode>,
+    pub enable_mint_burn: Option<bool>,
+    pub max_supply: Option<U256>,
+    pub transfer_filter_mode: Option<TransferFilterMode>,
+    pub proposal_threshold: Option<u64>,
+    /// Delay in milliseconds.
+    pub timelock_delay: Option<u64>,
+    pub security_lists: SecurityLists,
+}
+
+impl InstallArgs {
+    /// Creates install arguments with only the required ones set.
+    pub fn new(name: &str, symbol: &str, decimals: u8, total_supply: U256) -> Self {
+        InstallArgs {
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            decimals,
+            total_supply,
+            events_mode: None,
+            enable_mint_burn: None,
+            max_supply: None,
+            transfer_filter_mode: None,
+            proposal_threshold: None,
+            timelock_delay: None,
+            security_lists: SecurityLists::default(),
+        }
+    }
+
+    /// Builds the runtime arguments to install the token with.
+    pub fn to_runtime_args(&self) -> Result<RuntimeArgs, Error> {
+        let mut args = RuntimeArgs::new();
+        args.insert(NAME, self.name.clone())?;
+        args.insert(SYMBOL, self.symbol.clone())?;
+        args.insert(DECIMALS, self.decimals)?;
+        args.insert(TOTAL_SUPPLY, self.total_supply)?;
+        if let Some(events_mode) = self.events_mode {
+            args.insert(EVENTS_MODE, events_mode as u8)?;
+        }
+        if let Some(enable_mint_burn) = self.enable_mint_burn {
+            args.insert(ENABLE_MINT_BURN, u8::from(enable_mint_burn))?;
+        }
+        if let Some(max_supply) = self.max_supply {
+            args.insert(MAX_SUPPLY, max_supply)?;
+        }
+        if let Some(transfer_filter_mode) = self.transfer_filter_mode {
+            args.insert(TRANSFER_FILTER_MODE, transfer_filter_mode as u8)?;
+        }
+        if let Some(proposal_threshold) = self.proposal_threshold {
+            args.insert(PROPOSAL_THRESHOLD, proposal_threshold)?;
+        }
+        if let Some(timelock_delay) = self.timelock_delay {
+            args.insert(TIMELOCK_DELAY, timelock_delay)?;
+        }
+        self.security_lists.insert_into(&mut args)?;
+        Ok(args)
+    }
+}
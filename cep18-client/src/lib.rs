@@ -0,0 +1,16 @@
+//! Typed builders for the runtime arguments of CEP-18 entry points, along with the dictionary item
+//! keys needed to query balances and allowances, so Rust callers do not have to hand-build
+//! `runtime_args!` from copied string constants.
+//!
+//! Builders return an [`EntryPointCall`] which can be turned into a deploy, or into an
+//! `ExecuteRequestBuilder::contract_call_by_hash` request when testing against
+//! `InMemoryWasmTestBuilder`.
+pub mod calls;
+pub mod dictionary_keys;
+mod error;
+pub mod install;
+
+pub use calls::EntryPointCall;
+pub use cep18_types::{constants, EventsMode, TransferFilterMode};
+pub use error::Error;
+pub use install::{InstallArgs, SecurityLists};
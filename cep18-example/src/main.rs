@@ -0,0 +1,315 @@
+//! Example CEP-18 token exporting the entry points of the `cep18` library along with an
+//! `airdrop` entry point, which lets Admins mint a fixed amount of tokens to a recipient.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::String, vec};
+
+use casper_contract::contract_api::runtime;
+use casper_types::{
+    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, Key, Parameter, U256,
+};
+use cep18::{constants::RECIPIENT, exports, utils, ContractHooks, SecurityBadge};
+
+const AIRDROP_ENTRY_POINT_NAME: &str = "airdrop";
+const AIRDROP_AMOUNT: u64 = 100;
+
+#[no_mangle]
+pub extern "C" fn name() {
+    exports::name()
+}
+
+#[no_mangle]
+pub extern "C" fn symbol() {
+    exports::symbol()
+}
+
+#[no_mangle]
+pub extern "C" fn decimals() {
+    exports::decimals()
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply() {
+    exports::total_supply()
+}
+
+#[no_mangle]
+pub extern "C" fn max_supply() {
+    exports::max_supply()
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    exports::balance_of()
+}
+
+#[no_mangle]
+pub extern "C" fn holder_count() {
+    exports::holder_count()
+}
+
+#[no_mangle]
+pub extern "C" fn holder_at() {
+    exports::holder_at()
+}
+
+#[no_mangle]
+pub extern "C" fn index_accounts() {
+    exports::index_accounts()
+}
+
+#[no_mangle]
+pub extern "C" fn allowance() {
+    exports::allowance()
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    exports::approve()
+}
+
+#[no_mangle]
+pub extern "C" fn decrease_allowance() {
+    exports::decrease_allowance()
+}
+
+#[no_mangle]
+pub extern "C" fn increase_allowance() {
+    exports::increase_allowance()
+}
+
+#[no_mangle]
+pub extern "C" fn permit() {
+    exports::permit()
+}
+
+#[no_mangle]
+pub extern "C" fn nonce_of() {
+    exports::nonce_of()
+}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    exports::transfer()
+}
+
+#[no_mangle]
+pub extern "C" fn batch_transfer() {
+    exports::batch_transfer()
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    exports::transfer_from()
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_and_call() {
+    exports::transfer_and_call()
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from_and_call() {
+    exports::transfer_from_and_call()
+}
+
+#[no_mangle]
+pub extern "C" fn mint() {
+    exports::mint()
+}
+
+#[no_mangle]
+pub extern "C" fn batch_mint() {
+    exports::batch_mint()
+}
+
+#[no_mangle]
+pub extern "C" fn set_minter_allowance() {
+    exports::set_minter_allowance()
+}
+
+#[no_mangle]
+pub extern "C" fn minter_allowance() {
+    exports::minter_allowance()
+}
+
+#[no_mangle]
+pub extern "C" fn burn() {
+    exports::burn()
+}
+
+#[no_mangle]
+pub extern "C" fn burn_from() {
+    exports::burn_from()
+}
+
+#[no_mangle]
+pub extern "C" fn init() {
+    exports::init()
+}
+
+#[no_mangle]
+pub extern "C" fn change_security() {
+    exports::change_security()
+}
+
+#[no_mangle]
+pub extern "C" fn get_badge() {
+    exports::get_badge()
+}
+
+#[no_mangle]
+pub extern "C" fn is_minter() {
+    exports::is_minter()
+}
+
+#[no_mangle]
+pub extern "C" fn is_admin() {
+    exports::is_admin()
+}
+
+#[no_mangle]
+pub extern "C" fn role_member_count() {
+    exports::role_member_count()
+}
+
+#[no_mangle]
+pub extern "C" fn role_member_at() {
+    exports::role_member_at()
+}
+
+#[no_mangle]
+pub extern "C" fn propose_admin() {
+    exports::propose_admin()
+}
+
+#[no_mangle]
+pub extern "C" fn accept_admin() {
+    exports::accept_admin()
+}
+
+#[no_mangle]
+pub extern "C" fn pause() {
+    exports::pause()
+}
+
+#[no_mangle]
+pub extern "C" fn unpause() {
+    exports::unpause()
+}
+
+#[no_mangle]
+pub extern "C" fn propose() {
+    exports::propose()
+}
+
+#[no_mangle]
+pub extern "C" fn approve_proposal() {
+    exports::approve_proposal()
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_proposal() {
+    exports::cancel_proposal()
+}
+
+#[no_mangle]
+pub extern "C" fn schedule() {
+    exports::schedule()
+}
+
+#[no_mangle]
+pub extern "C" fn execute() {
+    exports::execute()
+}
+
+#[no_mangle]
+pub extern "C" fn cancel() {
+    exports::cancel()
+}
+
+#[no_mangle]
+pub extern "C" fn snapshot() {
+    exports::snapshot()
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of_at() {
+    exports::balance_of_at()
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply_at() {
+    exports::total_supply_at()
+}
+
+#[no_mangle]
+pub extern "C" fn delegate() {
+    exports::delegate()
+}
+
+#[no_mangle]
+pub extern "C" fn delegates() {
+    exports::delegates()
+}
+
+#[no_mangle]
+pub extern "C" fn get_votes() {
+    exports::get_votes()
+}
+
+#[no_mangle]
+pub extern "C" fn get_past_votes() {
+    exports::get_past_votes()
+}
+
+#[no_mangle]
+pub extern "C" fn freeze_account() {
+    exports::freeze_account()
+}
+
+#[no_mangle]
+pub extern "C" fn unfreeze_account() {
+    exports::unfreeze_account()
+}
+
+#[no_mangle]
+pub extern "C" fn add_to_whitelist() {
+    exports::add_to_whitelist()
+}
+
+#[no_mangle]
+pub extern "C" fn remove_from_whitelist() {
+    exports::remove_from_whitelist()
+}
+
+#[no_mangle]
+pub extern "C" fn migrate() {
+    exports::migrate()
+}
+
+/// Admin EntryPoint to mint `AIRDROP_AMOUNT` tokens to `recipient`.
+#[no_mangle]
+pub extern "C" fn airdrop() {
+    utils::sec_check(vec![SecurityBadge::Admin]);
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    cep18::mint(recipient, U256::from(AIRDROP_AMOUNT));
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let hooks = ContractHooks {
+        entry_points: vec![EntryPoint::new(
+            String::from(AIRDROP_ENTRY_POINT_NAME),
+            vec![Parameter::new(RECIPIENT, Key::cl_type())],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        )],
+        ..Default::default()
+    };
+    cep18::install_or_upgrade(hooks)
+}
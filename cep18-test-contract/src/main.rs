@@ -15,8 +15,9 @@ use casper_contract::{
 };
 
 use casper_types::{
-    bytesrepr::ToBytes, runtime_args, CLTyped, ContractHash, EntryPoint, EntryPointAccess,
-    EntryPointType, EntryPoints, Key, Parameter, RuntimeArgs, U256,
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, CLTyped, CLValue, ContractHash, EntryPoint, EntryPointAccess, EntryPointType,
+    EntryPoints, Key, Parameter, RuntimeArgs, U256,
 };
 
 const CHECK_TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "check_total_supply";
@@ -25,10 +26,31 @@ const TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_as_stored_c
 const APPROVE_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "approve_as_stored_contract";
 const TRANSFER_FROM_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_from_as_stored_contract";
 const CHECK_ALLOWANCE_OF_ENTRY_POINT_NAME: &str = "check_allowance_of";
+const CHECK_BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "check_balance_of_at";
+const CHECK_TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "check_total_supply_at";
+const CHECK_VOTES_ENTRY_POINT_NAME: &str = "check_votes";
+const CHECK_PAST_VOTES_ENTRY_POINT_NAME: &str = "check_past_votes";
+const CHECK_IS_MINTER_ENTRY_POINT_NAME: &str = "check_is_minter";
+const CHECK_IS_ADMIN_ENTRY_POINT_NAME: &str = "check_is_admin";
+const CHECK_BADGE_ENTRY_POINT_NAME: &str = "check_badge";
+const CHECK_ROLE_MEMBER_COUNT_ENTRY_POINT_NAME: &str = "check_role_member_count";
+const CHECK_ROLE_MEMBER_AT_ENTRY_POINT_NAME: &str = "check_role_member_at";
+const CHECK_HOLDER_COUNT_ENTRY_POINT_NAME: &str = "check_holder_count";
+const CHECK_HOLDER_AT_ENTRY_POINT_NAME: &str = "check_holder_at";
+const ON_CEP18_RECEIVED_ENTRY_POINT_NAME: &str = "on_cep18_received";
 const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
 const ADDRESS_RUNTIME_ARG_NAME: &str = "address";
 const OWNER_RUNTIME_ARG_NAME: &str = "owner";
 const SPENDER_RUNTIME_ARG_NAME: &str = "spender";
+const SNAPSHOT_ID_RUNTIME_ARG_NAME: &str = "snapshot_id";
+const ACCOUNT_RUNTIME_ARG_NAME: &str = "account";
+const TIMESTAMP_RUNTIME_ARG_NAME: &str = "timestamp";
+const BADGE_RUNTIME_ARG_NAME: &str = "badge";
+const INDEX_RUNTIME_ARG_NAME: &str = "index";
+const OPERATOR_RUNTIME_ARG_NAME: &str = "operator";
+const FROM_RUNTIME_ARG_NAME: &str = "from";
+const DATA_RUNTIME_ARG_NAME: &str = "data";
+const REJECT_DATA: &[u8] = b"reject";
 const RESULT_KEY: &str = "result";
 const CEP18_TEST_CALL_KEY: &str = "cep18_test_contract";
 
@@ -38,6 +60,17 @@ const AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
 const APPROVE_ENTRY_POINT_NAME: &str = "approve";
 const TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "total_supply";
 const BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
+const BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "balance_of_at";
+const TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "total_supply_at";
+const GET_VOTES_ENTRY_POINT_NAME: &str = "get_votes";
+const GET_PAST_VOTES_ENTRY_POINT_NAME: &str = "get_past_votes";
+const IS_MINTER_ENTRY_POINT_NAME: &str = "is_minter";
+const IS_ADMIN_ENTRY_POINT_NAME: &str = "is_admin";
+const GET_BADGE_ENTRY_POINT_NAME: &str = "get_badge";
+const ROLE_MEMBER_COUNT_ENTRY_POINT_NAME: &str = "role_member_count";
+const ROLE_MEMBER_AT_ENTRY_POINT_NAME: &str = "role_member_at";
+const HOLDER_COUNT_ENTRY_POINT_NAME: &str = "holder_count";
+const HOLDER_AT_ENTRY_POINT_NAME: &str = "holder_at";
 const TRANSFER_ENTRY_POINT_NAME: &str = "transfer";
 const TRANSFER_FROM_ENTRY_POINT_NAME: &str = "transfer_from";
 
@@ -105,6 +138,222 @@ extern "C" fn check_allowance_of() {
     store_result(result);
 }
 
+#[no_mangle]
+extern "C" fn check_balance_of_at() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let address: Key = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_RUNTIME_ARG_NAME);
+
+    let balance_at_args = runtime_args! {
+        ADDRESS_RUNTIME_ARG_NAME => address,
+        SNAPSHOT_ID_RUNTIME_ARG_NAME => snapshot_id,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        BALANCE_OF_AT_ENTRY_POINT_NAME,
+        balance_at_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_total_supply_at() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_RUNTIME_ARG_NAME);
+
+    let total_supply_at_args = runtime_args! {
+        SNAPSHOT_ID_RUNTIME_ARG_NAME => snapshot_id,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        TOTAL_SUPPLY_AT_ENTRY_POINT_NAME,
+        total_supply_at_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_votes() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    let votes_args = runtime_args! {
+        ACCOUNT_RUNTIME_ARG_NAME => account,
+    };
+    let result: U256 =
+        runtime::call_contract(token_contract, GET_VOTES_ENTRY_POINT_NAME, votes_args);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_past_votes() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let timestamp: u64 = runtime::get_named_arg(TIMESTAMP_RUNTIME_ARG_NAME);
+
+    let past_votes_args = runtime_args! {
+        ACCOUNT_RUNTIME_ARG_NAME => account,
+        TIMESTAMP_RUNTIME_ARG_NAME => timestamp,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        GET_PAST_VOTES_ENTRY_POINT_NAME,
+        past_votes_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_is_minter() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    let is_minter_args = runtime_args! {
+        ACCOUNT_RUNTIME_ARG_NAME => account,
+    };
+    let result: bool =
+        runtime::call_contract(token_contract, IS_MINTER_ENTRY_POINT_NAME, is_minter_args);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_is_admin() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    let is_admin_args = runtime_args! {
+        ACCOUNT_RUNTIME_ARG_NAME => account,
+    };
+    let result: bool =
+        runtime::call_contract(token_contract, IS_ADMIN_ENTRY_POINT_NAME, is_admin_args);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_badge() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    let get_badge_args = runtime_args! {
+        ACCOUNT_RUNTIME_ARG_NAME => account,
+    };
+    let result: Option<u8> =
+        runtime::call_contract(token_contract, GET_BADGE_ENTRY_POINT_NAME, get_badge_args);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_role_member_count() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let badge: u8 = runtime::get_named_arg(BADGE_RUNTIME_ARG_NAME);
+
+    let role_member_count_args = runtime_args! {
+        BADGE_RUNTIME_ARG_NAME => badge,
+    };
+    let result: u64 = runtime::call_contract(
+        token_contract,
+        ROLE_MEMBER_COUNT_ENTRY_POINT_NAME,
+        role_member_count_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_role_member_at() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let badge: u8 = runtime::get_named_arg(BADGE_RUNTIME_ARG_NAME);
+    let index: u64 = runtime::get_named_arg(INDEX_RUNTIME_ARG_NAME);
+
+    let role_member_at_args = runtime_args! {
+        BADGE_RUNTIME_ARG_NAME => badge,
+        INDEX_RUNTIME_ARG_NAME => index,
+    };
+    let result: Key = runtime::call_contract(
+        token_contract,
+        ROLE_MEMBER_AT_ENTRY_POINT_NAME,
+        role_member_at_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_holder_count() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let result: u64 = runtime::call_contract(
+        token_contract,
+        HOLDER_COUNT_ENTRY_POINT_NAME,
+        RuntimeArgs::default(),
+    );
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_holder_at() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let index: u64 = runtime::get_named_arg(INDEX_RUNTIME_ARG_NAME);
+
+    let holder_at_args = runtime_args! {
+        INDEX_RUNTIME_ARG_NAME => index,
+    };
+    let result: Key =
+        runtime::call_contract(token_contract, HOLDER_AT_ENTRY_POINT_NAME, holder_at_args);
+
+    store_result(result);
+}
+
 #[no_mangle]
 extern "C" fn transfer_as_stored_contract() {
     let token_contract: ContractHash = ContractHash::new(
@@ -165,6 +414,20 @@ extern "C" fn approve_as_stored_contract() {
     runtime::call_contract::<()>(token_contract, APPROVE_ENTRY_POINT_NAME, approve_args);
 }
 
+#[no_mangle]
+extern "C" fn on_cep18_received() {
+    let operator: Key = runtime::get_named_arg(OPERATOR_RUNTIME_ARG_NAME);
+    let from: Key = runtime::get_named_arg(FROM_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let data: Bytes = runtime::get_named_arg(DATA_RUNTIME_ARG_NAME);
+
+    let accepted = data.as_slice() != REJECT_DATA;
+    if accepted {
+        store_result((operator, from, amount));
+    }
+    runtime::ret(CLValue::from_t(accepted).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn call() {
     let mut entry_points = EntryPoints::new();
@@ -199,6 +462,119 @@ pub extern "C" fn call() {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
+    let check_balance_of_at_entrypoint = EntryPoint::new(
+        String::from(CHECK_BALANCE_OF_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(SNAPSHOT_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_total_supply_at_entrypoint = EntryPoint::new(
+        String::from(CHECK_TOTAL_SUPPLY_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(SNAPSHOT_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_votes_entrypoint = EntryPoint::new(
+        String::from(CHECK_VOTES_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_past_votes_entrypoint = EntryPoint::new(
+        String::from(CHECK_PAST_VOTES_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(TIMESTAMP_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_is_minter_entrypoint = EntryPoint::new(
+        String::from(CHECK_IS_MINTER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_is_admin_entrypoint = EntryPoint::new(
+        String::from(CHECK_IS_ADMIN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_badge_entrypoint = EntryPoint::new(
+        String::from(CHECK_BADGE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_role_member_count_entrypoint = EntryPoint::new(
+        String::from(CHECK_ROLE_MEMBER_COUNT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(BADGE_RUNTIME_ARG_NAME, u8::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_role_member_at_entrypoint = EntryPoint::new(
+        String::from(CHECK_ROLE_MEMBER_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(BADGE_RUNTIME_ARG_NAME, u8::cl_type()),
+            Parameter::new(INDEX_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_holder_count_entrypoint = EntryPoint::new(
+        String::from(CHECK_HOLDER_COUNT_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            TOKEN_CONTRACT_RUNTIME_ARG_NAME,
+            ContractHash::cl_type(),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_holder_at_entrypoint = EntryPoint::new(
+        String::from(CHECK_HOLDER_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(INDEX_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
 
     let transfer_as_stored_contract_entrypoint = EntryPoint::new(
         String::from(TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME),
@@ -237,12 +613,37 @@ pub extern "C" fn call() {
         EntryPointType::Contract,
     );
 
+    let on_cep18_received_entrypoint = EntryPoint::new(
+        String::from(ON_CEP18_RECEIVED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OPERATOR_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(FROM_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     entry_points.add_entry_point(check_total_supply_entrypoint);
     entry_points.add_entry_point(check_balance_of_entrypoint);
     entry_points.add_entry_point(check_allowance_of_entrypoint);
+    entry_points.add_entry_point(check_balance_of_at_entrypoint);
+    entry_points.add_entry_point(check_total_supply_at_entrypoint);
+    entry_points.add_entry_point(check_votes_entrypoint);
+    entry_points.add_entry_point(check_past_votes_entrypoint);
+    entry_points.add_entry_point(check_is_minter_entrypoint);
+    entry_points.add_entry_point(check_is_admin_entrypoint);
+    entry_points.add_entry_point(check_badge_entrypoint);
+    entry_points.add_entry_point(check_role_member_count_entrypoint);
+    entry_points.add_entry_point(check_role_member_at_entrypoint);
+    entry_points.add_entry_point(check_holder_count_entrypoint);
+    entry_points.add_entry_point(check_holder_at_entrypoint);
     entry_points.add_entry_point(transfer_as_stored_contract_entrypoint);
     entry_points.add_entry_point(approve_as_stored_contract_entrypoint);
     entry_points.add_entry_point(transfer_from_as_stored_contract_entrypoint);
+    entry_points.add_entry_point(on_cep18_received_entrypoint);
 
     let (_contract_hash, _version) = storage::new_contract(
         entry_points,
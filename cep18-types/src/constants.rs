@@ -0,0 +1,256 @@
+//! Constants used by the CEP-18 contract.
+
+/// Name of named-key for `name`.
+pub const NAME: &str = "name";
+/// Name of named-key for `symbol`
+pub const SYMBOL: &str = "symbol";
+/// Name of named-key for `decimals`
+pub const DECIMALS: &str = "decimals";
+/// Name of dictionary-key for `balances`
+pub const BALANCES: &str = "balances";
+/// Name of dictionary-key for `allowances`
+pub const ALLOWANCES: &str = "allowances";
+/// Name of named-key for `total_supply`
+pub const TOTAL_SUPPLY: &str = "total_supply";
+/// Name of named-key for `max_supply`
+pub const MAX_SUPPLY: &str = "max_supply";
+/// Name of named-key for `paused`
+pub const PAUSED: &str = "paused";
+/// Name of dictionary-key for `frozen_accounts`
+pub const FROZEN_ACCOUNTS: &str = "frozen_accounts";
+/// Name of dictionary-key for `whitelist`
+pub const WHITELIST: &str = "whitelist";
+/// Name of dictionary-key for `nonces`
+pub const NONCES: &str = "nonces";
+/// Name of dictionary-key for `allowance_expirations`
+pub const ALLOWANCE_EXPIRATIONS: &str = "allowance_expirations";
+/// Name of named-key for `holder_count`
+pub const HOLDER_COUNT: &str = "holder_count";
+/// Name of dictionary-key for `holders`
+pub const HOLDERS: &str = "holders";
+/// Name of dictionary-key for `holder_indices`
+pub const HOLDER_INDICES: &str = "holder_indices";
+/// Name of named-key for `snapshot_id`
+pub const SNAPSHOT_ID: &str = "snapshot_id";
+/// Name of dictionary-key for `balance_snapshots`
+pub const BALANCE_SNAPSHOTS: &str = "balance_snapshots";
+/// Name of dictionary-key for `balance_snapshot_counts`
+pub const BALANCE_SNAPSHOT_COUNTS: &str = "balance_snapshot_counts";
+/// Name of dictionary-key for `total_supply_snapshots`
+pub const TOTAL_SUPPLY_SNAPSHOTS: &str = "total_supply_snapshots";
+/// Name of named-key for `total_supply_snapshot_count`
+pub const TOTAL_SUPPLY_SNAPSHOT_COUNT: &str = "total_supply_snapshot_count";
+/// Name of dictionary-key for `delegates`
+pub const DELEGATES: &str = "delegates";
+/// Name of dictionary-key for `vote_checkpoints`
+pub const VOTE_CHECKPOINTS: &str = "vote_checkpoints";
+/// Name of dictionary-key for `vote_checkpoint_counts`
+pub const VOTE_CHECKPOINT_COUNTS: &str = "vote_checkpoint_counts";
+/// Name of named-key for `pending_admin`
+pub const PENDING_ADMIN: &str = "pending_admin";
+/// Name of dictionary-key for `role_members`
+pub const ROLE_MEMBERS: &str = "role_members";
+/// Name of dictionary-key for `role_member_counts`
+pub const ROLE_MEMBER_COUNTS: &str = "role_member_counts";
+/// Name of dictionary-key for `role_member_indices`
+pub const ROLE_MEMBER_INDICES: &str = "role_member_indices";
+/// Name of dictionary-key for `proposals`
+pub const PROPOSALS: &str = "proposals";
+/// Name of named-key for `proposal_count`
+pub const PROPOSAL_COUNT: &str = "proposal_count";
+/// Name of named-key for `proposal_threshold`
+pub const PROPOSAL_THRESHOLD: &str = "proposal_threshold";
+/// Name of named-key for `timelock_delay`
+pub const TIMELOCK_DELAY: &str = "timelock_delay";
+/// Name of dictionary-key for `operations`
+pub const OPERATIONS: &str = "operations";
+/// Name of named-key for `operation_count`
+pub const OPERATION_COUNT: &str = "operation_count";
+/// Name of named-key for `upgrade_ready`
+pub const UPGRADE_READY: &str = "upgrade_ready";
+/// Name of named-key for `migrated_contract`
+pub const MIGRATED_CONTRACT: &str = "migrated_contract";
+/// Name of dictionary-key for `minter_allowances`
+pub const MINTER_ALLOWANCES: &str = "minter_allowances";
+
+pub const HASH_KEY_NAME_PREFIX: &str = "cep18_contract_package_";
+pub const ACCESS_KEY_NAME_PREFIX: &str = "cep18_contract_package_access_";
+pub const CONTRACT_NAME_PREFIX: &str = "cep18_contract_hash_";
+pub const CONTRACT_VERSION_PREFIX: &str = "cep18_contract_version_";
+pub const UPGRADE_READY_PREFIX: &str = "cep18_upgrade_ready_";
+
+/// Name of `name` entry point.
+pub const NAME_ENTRY_POINT_NAME: &str = "name";
+/// Name of `symbol` entry point.
+pub const SYMBOL_ENTRY_POINT_NAME: &str = "symbol";
+/// Name of `decimals` entry point.
+pub const DECIMALS_ENTRY_POINT_NAME: &str = "decimals";
+/// Name of `balance_of` entry point.
+pub const BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
+/// Name of `transfer` entry point.
+pub const TRANSFER_ENTRY_POINT_NAME: &str = "transfer";
+/// Name of `approve` entry point.
+pub const APPROVE_ENTRY_POINT_NAME: &str = "approve";
+/// Name of `allowance` entry point.
+pub const ALLOWANCE_ENTRY_POINT_NAME: &str = "allowance";
+/// Name of `transfer_from` entry point.
+pub const TRANSFER_FROM_ENTRY_POINT_NAME: &str = "transfer_from";
+/// Name of `batch_transfer` entry point.
+pub const BATCH_TRANSFER_ENTRY_POINT_NAME: &str = "batch_transfer";
+/// Name of `transfer_and_call` entry point.
+pub const TRANSFER_AND_CALL_ENTRY_POINT_NAME: &str = "transfer_and_call";
+/// Name of `transfer_from_and_call` entry point.
+pub const TRANSFER_FROM_AND_CALL_ENTRY_POINT_NAME: &str = "transfer_from_and_call";
+/// Name of the `on_cep18_received` entry point of contracts receiving tokens.
+pub const ON_CEP18_RECEIVED_ENTRY_POINT_NAME: &str = "on_cep18_received";
+/// Name of `total_supply` entry point.
+pub const TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "total_supply";
+/// Name of `max_supply` entry point.
+pub const MAX_SUPPLY_ENTRY_POINT_NAME: &str = "max_supply";
+/// Name of `transfer_from` entry point.
+pub const MINT_ENTRY_POINT_NAME: &str = "mint";
+/// Name of `batch_mint` entry point.
+pub const BATCH_MINT_ENTRY_POINT_NAME: &str = "batch_mint";
+/// Name of `burn` entry point.
+pub const BURN_ENTRY_POINT_NAME: &str = "burn";
+/// Name of `burn_from` entry point.
+pub const BURN_FROM_ENTRY_POINT_NAME: &str = "burn_from";
+/// Name of `init` entry point.
+pub const INIT_ENTRY_POINT_NAME: &str = "init";
+/// Name of `change_security` entry point.
+pub const CHANGE_SECURITY_ENTRY_POINT_NAME: &str = "change_security";
+/// Name of `migrate` entry point.
+pub const MIGRATE_ENTRY_POINT_NAME: &str = "migrate";
+/// Name of `pause` entry point.
+pub const PAUSE_ENTRY_POINT_NAME: &str = "pause";
+/// Name of `unpause` entry point.
+pub const UNPAUSE_ENTRY_POINT_NAME: &str = "unpause";
+/// Name of `freeze_account` entry point.
+pub const FREEZE_ACCOUNT_ENTRY_POINT_NAME: &str = "freeze_account";
+/// Name of `unfreeze_account` entry point.
+pub const UNFREEZE_ACCOUNT_ENTRY_POINT_NAME: &str = "unfreeze_account";
+/// Name of `add_to_whitelist` entry point.
+pub const ADD_TO_WHITELIST_ENTRY_POINT_NAME: &str = "add_to_whitelist";
+/// Name of `remove_from_whitelist` entry point.
+pub const REMOVE_FROM_WHITELIST_ENTRY_POINT_NAME: &str = "remove_from_whitelist";
+/// Name of `permit` entry point.
+pub const PERMIT_ENTRY_POINT_NAME: &str = "permit";
+/// Name of `nonce_of` entry point.
+pub const NONCE_OF_ENTRY_POINT_NAME: &str = "nonce_of";
+/// Name of `holder_count` entry point.
+pub const HOLDER_COUNT_ENTRY_POINT_NAME: &str = "holder_count";
+/// Name of `holder_at` entry point.
+pub const HOLDER_AT_ENTRY_POINT_NAME: &str = "holder_at";
+/// Name of `index_accounts` entry point.
+pub const INDEX_ACCOUNTS_ENTRY_POINT_NAME: &str = "index_accounts";
+/// Name of `snapshot` entry point.
+pub const SNAPSHOT_ENTRY_POINT_NAME: &str = "snapshot";
+/// Name of `balance_of_at` entry point.
+pub const BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "balance_of_at";
+/// Name of `total_supply_at` entry point.
+pub const TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "total_supply_at";
+/// Name of `delegate` entry point.
+pub const DELEGATE_ENTRY_POINT_NAME: &str = "delegate";
+/// Name of `delegates` entry point.
+pub const DELEGATES_ENTRY_POINT_NAME: &str = "delegates";
+/// Name of `get_votes` entry point.
+pub const GET_VOTES_ENTRY_POINT_NAME: &str = "get_votes";
+/// Name of `get_past_votes` entry point.
+pub const GET_PAST_VOTES_ENTRY_POINT_NAME: &str = "get_past_votes";
+/// Name of `propose_admin` entry point.
+pub const PROPOSE_ADMIN_ENTRY_POINT_NAME: &str = "propose_admin";
+/// Name of `accept_admin` entry point.
+pub const ACCEPT_ADMIN_ENTRY_POINT_NAME: &str = "accept_admin";
+/// Name of `get_badge` entry point.
+pub const GET_BADGE_ENTRY_POINT_NAME: &str = "get_badge";
+/// Name of `is_minter` entry point.
+pub const IS_MINTER_ENTRY_POINT_NAME: &str = "is_minter";
+/// Name of `is_admin` entry point.
+pub const IS_ADMIN_ENTRY_POINT_NAME: &str = "is_admin";
+/// Name of `role_member_count` entry point.
+pub const ROLE_MEMBER_COUNT_ENTRY_POINT_NAME: &str = "role_member_count";
+/// Name of `role_member_at` entry point.
+pub const ROLE_MEMBER_AT_ENTRY_POINT_NAME: &str = "role_member_at";
+/// Name of `propose` entry point.
+pub const PROPOSE_ENTRY_POINT_NAME: &str = "propose";
+/// Name of `approve_proposal` entry point.
+pub const APPROVE_PROPOSAL_ENTRY_POINT_NAME: &str = "approve_proposal";
+/// Name of `cancel_proposal` entry point.
+pub const CANCEL_PROPOSAL_ENTRY_POINT_NAME: &str = "cancel_proposal";
+/// Name of `schedule` entry point.
+pub const SCHEDULE_ENTRY_POINT_NAME: &str = "schedule";
+/// Name of `execute` entry point.
+pub const EXECUTE_ENTRY_POINT_NAME: &str = "execute";
+/// Name of `cancel` entry point.
+pub const CANCEL_ENTRY_POINT_NAME: &str = "cancel";
+/// Name of `set_minter_allowance` entry point.
+pub const SET_MINTER_ALLOWANCE_ENTRY_POINT_NAME: &str = "set_minter_allowance";
+/// Name of `minter_allowance` entry point.
+pub const MINTER_ALLOWANCE_ENTRY_POINT_NAME: &str = "minter_allowance";
+
+pub const INCREASE_ALLOWANCE_ENTRY_POINT_NAME: &str = "increase_allowance";
+pub const DECREASE_ALLOWANCE_ENTRY_POINT_NAME: &str = "decrease_allowance";
+
+/// Name of `address` runtime argument.
+pub const ADDRESS: &str = "address";
+/// Name of `owner` runtime argument.
+pub const OWNER: &str = "owner";
+/// Name of `spender` runtime argument.
+pub const SPENDER: &str = "spender";
+/// Name of `amount` runtime argument.
+pub const AMOUNT: &str = "amount";
+/// Name of `recipient` runtime argument.
+pub const RECIPIENT: &str = "recipient";
+/// Name of `recipients` runtime argument.
+pub const RECIPIENTS: &str = "recipients";
+/// Name of `accounts` runtime argument.
+pub const ACCOUNTS: &str = "accounts";
+/// Name of `amounts` runtime argument.
+pub const AMOUNTS: &str = "amounts";
+/// Name of `operator` runtime argument.
+pub const OPERATOR: &str = "operator";
+/// Name of `from` runtime argument.
+pub const FROM: &str = "from";
+/// Name of `data` runtime argument.
+pub const DATA: &str = "data";
+/// Name of `owner_public_key` runtime argument.
+pub const OWNER_PUBLIC_KEY: &str = "owner_public_key";
+/// Name of `deadline` runtime argument.
+pub const DEADLINE: &str = "deadline";
+/// Name of `nonce` runtime argument.
+pub const NONCE: &str = "nonce";
+/// Name of `signature` runtime argument.
+pub const SIGNATURE: &str = "signature";
+/// Name of `expires_at` runtime argument.
+pub const EXPIRES_AT: &str = "expires_at";
+/// Name of `index` runtime argument.
+pub const INDEX: &str = "index";
+/// Name of `snapshot_id` runtime argument.
+pub const SNAPSHOT_ID_ARG: &str = "snapshot_id";
+/// Name of `delegatee` runtime argument.
+pub const DELEGATEE: &str = "delegatee";
+/// Name of `account` runtime argument.
+pub const ACCOUNT: &str = "account";
+/// Name of `timestamp` runtime argument.
+pub const TIMESTAMP: &str = "timestamp";
+/// Name of `badge` runtime argument.
+pub const BADGE: &str = "badge";
+/// Name of `action` runtime argument.
+pub const ACTION: &str = "action";
+/// Name of `proposal_id` runtime argument.
+pub const PROPOSAL_ID: &str = "proposal_id";
+/// Name of `threshold` runtime argument.
+pub const THRESHOLD: &str = "threshold";
+/// Name of `operation_id` runtime argument.
+pub const OPERATION_ID: &str = "operation_id";
+pub const PACKAGE_HASH: &str = "package_hash";
+pub const EVENTS_MODE: &str = "events_mode";
+pub const SECURITY_BADGES: &str = "security_badges";
+pub const ADMIN_LIST: &str = "admin_list";
+pub const MINTER_LIST: &str = "minter_list";
+pub const BURNER_LIST: &str = "burner_list";
+pub const NONE_LIST: &str = "none_list";
+pub const MINT_AND_BURN_LIST: &str = "mint_and_burn_list";
+pub const KYC_ADMIN_LIST: &str = "kyc_admin_list";
+pub const ENABLE_MINT_BURN: &str = "enable_mint_burn";
+pub const TRANSFER_FILTER_MODE: &str = "transfer_filter_mode";
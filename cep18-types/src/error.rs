@@ -0,0 +1,330 @@
+//! Errors the CEP-18 contract reverts with.
+use core::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+};
+
+use casper_types::ApiError;
+
+/// Errors that the contract can return.
+///
+/// When an `Error` is returned from a smart contract, it is converted to an [`ApiError::User`].
+///
+/// While the code consuming this contract needs to define further error variants, it can
+/// return those via the [`Error::User`] variant or equivalently via the [`ApiError::User`]
+/// variant.
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cep18Error {
+    /// CEP-18 contract called from within an invalid context.
+    InvalidContext = 60000,
+    /// Spender does not have enough balance.
+    InsufficientBalance = 60001,
+    /// Spender does not have enough allowance approved.
+    InsufficientAllowance = 60002,
+    /// Operation would cause an integer overflow.
+    Overflow = 60003,
+    /// A required package hash was not specified.
+    PackageHashMissing = 60004,
+    /// The package hash specified does not represent a package.
+    PackageHashNotPackage = 60005,
+    /// An invalid event mode was specified.
+    InvalidEventsMode = 60006,
+    /// The event mode required was not specified.
+    MissingEventsMode = 60007,
+    /// An unknown error occurred.
+    Phantom = 60008,
+    /// Failed to read the runtime arguments provided.
+    FailedToGetArgBytes = 60009,
+    /// The caller does not have sufficient security access.
+    InsufficientRights = 60010,
+    /// The list of Admin accounts provided is invalid.
+    InvalidAdminList = 60011,
+    /// The list of accounts that can mint tokens is invalid.
+    InvalidMinterList = 60012,
+    /// The list of accounts with no access rights is invalid.
+    InvalidNoneList = 60013,
+    /// The flag to enable the mint and burn mode is invalid.
+    InvalidEnableMBFlag = 60014,
+    /// This contract instance cannot be initialized again.
+    AlreadyInitialized = 60015,
+    ///  The mint and burn mode is disabled.
+    MintBurnDisabled = 60016,
+    CannotTargetSelfUser = 60017,
+    InvalidBurnTarget = 60018,
+    /// The contract hash of the version to upgrade was not found.
+    MissingContractHash = 60019,
+    /// The list of accounts that can burn tokens is invalid.
+    InvalidBurnerList = 60020,
+    /// The list of accounts that can mint and burn tokens is invalid.
+    InvalidMintAndBurnList = 60021,
+    /// The contract is paused.
+    Paused = 60022,
+    /// One of the accounts involved is frozen.
+    AccountFrozen = 60023,
+    /// An invalid transfer filter mode was specified.
+    InvalidTransferFilterMode = 60024,
+    /// The recipient is not on the whitelist.
+    RecipientNotWhitelisted = 60025,
+    /// The list of accounts that can manage the whitelist is invalid.
+    InvalidKycAdminList = 60026,
+    /// Operation would raise the total supply above the maximum supply.
+    ExceedsMaxSupply = 60027,
+    /// The maximum supply provided is invalid.
+    InvalidMaxSupply = 60028,
+    /// The lists of recipients and amounts have different lengths.
+    BatchLengthMismatch = 60029,
+    /// The permit deadline has passed.
+    PermitExpired = 60030,
+    /// The permit nonce does not match the owner's current nonce.
+    InvalidNonce = 60031,
+    /// The permit signature is not valid for the owner's public key.
+    InvalidSignature = 60032,
+    /// The allowance expiry is not a block time in the future.
+    InvalidAllowanceExpiry = 60033,
+    /// There is no holder at the requested index.
+    HolderIndexOutOfRange = 60034,
+    /// The snapshot id does not refer to a snapshot that was taken.
+    InvalidSnapshotId = 60035,
+    /// The requested block time is not in the past yet.
+    FutureLookup = 60036,
+    /// The change would leave the contract without any Admin.
+    LastAdmin = 60037,
+    /// The caller is not the account proposed as Admin.
+    NotPendingAdmin = 60038,
+    /// The number of Admin accounts provided is invalid.
+    InvalidAdminCount = 60039,
+    /// There is no member of the badge at the requested index.
+    RoleMemberIndexOutOfRange = 60040,
+    /// The action has to be proposed and approved by enough Admin accounts.
+    ProposalRequired = 60041,
+    /// The proposal threshold is zero or above the number of Admin accounts.
+    InvalidProposalThreshold = 60042,
+    /// The proposal id does not refer to a proposal that was made.
+    ProposalNotFound = 60043,
+    /// The proposal was already carried out.
+    ProposalAlreadyExecuted = 60044,
+    /// The caller already approved the proposal.
+    ProposalAlreadyApproved = 60045,
+    /// The proposed action is not one of the supported actions.
+    InvalidProposalAction = 60046,
+    /// The action has to be scheduled and executed once the timelock delay has passed.
+    TimelockRequired = 60047,
+    /// The operation id does not refer to an operation that was scheduled.
+    OperationNotFound = 60048,
+    /// The operation was already executed or cancelled.
+    OperationNotPending = 60049,
+    /// The timelock delay of the operation has not passed yet.
+    OperationNotReady = 60050,
+    /// The upgrade was not scheduled and executed through the timelock.
+    UpgradeNotScheduled = 60051,
+    /// The timelock delay provided is invalid.
+    InvalidTimelockDelay = 60052,
+    /// The minter has not enough allowance left to mint the amount.
+    MinterAllowanceExceeded = 60053,
+    /// The recipient of a transfer with a call is not a contract package.
+    RecipientNotContract = 60054,
+    /// The recipient contract rejected the tokens it received.
+    TransferRejected = 60055,
+    /// Admins are only added through `propose_admin` and `accept_admin`.
+    AdminGrantNotAllowed = 60056,
+    /// The proposal was cancelled.
+    ProposalCancelled = 60057,
+    /// Only the Admin that created the proposal can cancel it.
+    NotProposer = 60058,
+}
+
+impl From<Cep18Error> for ApiError {
+    fn from(error: Cep18Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+/// Recovers the error behind an [`ApiError::User`] code, handing back codes that are not CEP-18
+/// errors.
+impl TryFrom<u16> for Cep18Error {
+    type Error = u16;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            60000 => Ok(Cep18Error::InvalidContext),
+            60001 => Ok(Cep18Error::InsufficientBalance),
+            60002 => Ok(Cep18Error::InsufficientAllowance),
+            60003 => Ok(Cep18Error::Overflow),
+            60004 => Ok(Cep18Error::PackageHashMissing),
+            60005 => Ok(Cep18Error::PackageHashNotPackage),
+            60006 => Ok(Cep18Error::InvalidEventsMode),
+            60007 => Ok(Cep18Error::MissingEventsMode),
+            60008 => Ok(Cep18Error::Phantom),
+            60009 => Ok(Cep18Error::FailedToGetArgBytes),
+            60010 => Ok(Cep18Error::InsufficientRights),
+            60011 => Ok(Cep18Error::InvalidAdminList),
+            60012 => Ok(Cep18Error::InvalidMinterList),
+            60013 => Ok(Cep18Error::InvalidNoneList),
+            60014 => Ok(Cep18Error::InvalidEnableMBFlag),
+            60015 => Ok(Cep18Error::AlreadyInitialized),
+            60016 => Ok(Cep18Error::MintBurnDisabled),
+            60017 => Ok(Cep18Error::CannotTargetSelfUser),
+            60018 => Ok(Cep18Error::InvalidBurnTarget),
+            60019 => Ok(Cep18Error::MissingContractHash),
+            60020 => Ok(Cep18Error::InvalidBurnerList),
+            60021 => Ok(Cep18Error::InvalidMintAndBurnList),
+            60022 => Ok(Cep18Error::Paused),
+            60023 => Ok(Cep18Error::AccountFrozen),
+            60024 => Ok(Cep18Error::InvalidTransferFilterMode),
+            60025 => Ok(Cep18Error::RecipientNotWhitelisted),
+            60026 => Ok(Cep18Error::InvalidKycAdminList),
+            60027 => Ok(Cep18Error::ExceedsMaxSupply),
+            60028 => Ok(Cep18Error::InvalidMaxSupply),
+            60029 => Ok(Cep18Error::BatchLengthMismatch),
+            60030 => Ok(Cep18Error::PermitExpired),
+            60031 => Ok(Cep18Error::InvalidNonce),
+            60032 => Ok(Cep18Error::InvalidSignature),
+            60033 => Ok(Cep18Error::InvalidAllowanceExpiry),
+            60034 => Ok(Cep18Error::HolderIndexOutOfRange),
+            60035 => Ok(Cep18Error::InvalidSnapshotId),
+            60036 => Ok(Cep18Error::FutureLookup),
+            60037 => Ok(Cep18Error::LastAdmin),
+            60038 => Ok(Cep18Error::NotPendingAdmin),
+            60039 => Ok(Cep18Error::InvalidAdminCount),
+            60040 => Ok(Cep18Error::RoleMemberIndexOutOfRange),
+            60041 => Ok(Cep18Error::ProposalRequired),
+            60042 => Ok(Cep18Error::InvalidProposalThreshold),
+            60043 => Ok(Cep18Error::ProposalNotFound),
+            60044 => Ok(Cep18Error::ProposalAlreadyExecuted),
+            60045 => Ok(Cep18Error::ProposalAlreadyApproved),
+            60046 => Ok(Cep18Error::InvalidProposalAction),
+            60047 => Ok(Cep18Error::TimelockRequired),
+            60048 => Ok(Cep18Error::OperationNotFound),
+            60049 => Ok(Cep18Error::OperationNotPending),
+            60050 => Ok(Cep18Error::OperationNotReady),
+            60051 => Ok(Cep18Error::UpgradeNotScheduled),
+            60052 => Ok(Cep18Error::InvalidTimelockDelay),
+            60053 => Ok(Cep18Error::MinterAllowanceExceeded),
+            60054 => Ok(Cep18Error::RecipientNotContract),
+            60055 => Ok(Cep18Error::TransferRejected),
+            60056 => Ok(Cep18Error::AdminGrantNotAllowed),
+            60057 => Ok(Cep18Error::ProposalCancelled),
+            60058 => Ok(Cep18Error::NotProposer),
+            _ => Err(value),
+        }
+    }
+}
+
+impl Display for Cep18Error {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        let message = match self {
+            Cep18Error::InvalidContext => "CEP-18 contract called from within an invalid context",
+            Cep18Error::InsufficientBalance => "Spender does not have enough balance",
+            Cep18Error::InsufficientAllowance => "Spender does not have enough allowance approved",
+            Cep18Error::Overflow => "Operation would cause an integer overflow",
+            Cep18Error::PackageHashMissing => "A required package hash was not specified",
+            Cep18Error::PackageHashNotPackage => {
+                "The package hash specified does not represent a package"
+            }
+            Cep18Error::InvalidEventsMode => "An invalid event mode was specified",
+            Cep18Error::MissingEventsMode => "The event mode required was not specified",
+            Cep18Error::Phantom => "An unknown error occurred",
+            Cep18Error::FailedToGetArgBytes => "Failed to read the runtime arguments provided",
+            Cep18Error::InsufficientRights => "The caller does not have sufficient security access",
+            Cep18Error::InvalidAdminList => "The list of Admin accounts provided is invalid",
+            Cep18Error::InvalidMinterList => "The list of accounts that can mint tokens is invalid",
+            Cep18Error::InvalidNoneList => "The list of accounts with no access rights is invalid",
+            Cep18Error::InvalidEnableMBFlag => {
+                "The flag to enable the mint and burn mode is invalid"
+            }
+            Cep18Error::AlreadyInitialized => "This contract instance cannot be initialized again",
+            Cep18Error::MintBurnDisabled => "The mint and burn mode is disabled",
+            Cep18Error::CannotTargetSelfUser => "The caller cannot target itself",
+            Cep18Error::InvalidBurnTarget => "The caller cannot burn tokens of another owner",
+            Cep18Error::MissingContractHash => {
+                "The contract hash of the version to upgrade was not found"
+            }
+            Cep18Error::InvalidBurnerList => "The list of accounts that can burn tokens is invalid",
+            Cep18Error::InvalidMintAndBurnList => {
+                "The list of accounts that can mint and burn tokens is invalid"
+            }
+            Cep18Error::Paused => "The contract is paused",
+            Cep18Error::AccountFrozen => "One of the accounts involved is frozen",
+            Cep18Error::InvalidTransferFilterMode => {
+                "An invalid transfer filter mode was specified"
+            }
+            Cep18Error::RecipientNotWhitelisted => "The recipient is not on the whitelist",
+            Cep18Error::InvalidKycAdminList => {
+                "The list of accounts that can manage the whitelist is invalid"
+            }
+            Cep18Error::ExceedsMaxSupply => {
+                "Operation would raise the total supply above the maximum supply"
+            }
+            Cep18Error::InvalidMaxSupply => "The maximum supply provided is invalid",
+            Cep18Error::BatchLengthMismatch => {
+                "The lists of recipients and amounts have different lengths"
+            }
+            Cep18Error::PermitExpired => "The permit deadline has passed",
+            Cep18Error::InvalidNonce => "The permit nonce does not match the owner's current nonce",
+            Cep18Error::InvalidSignature => {
+                "The permit signature is not valid for the owner's public key"
+            }
+            Cep18Error::InvalidAllowanceExpiry => {
+                "The allowance expiry is not a block time in the future"
+            }
+            Cep18Error::HolderIndexOutOfRange => "There is no holder at the requested index",
+            Cep18Error::InvalidSnapshotId => {
+                "The snapshot id does not refer to a snapshot that was taken"
+            }
+            Cep18Error::FutureLookup => "The requested block time is not in the past yet",
+            Cep18Error::LastAdmin => "The change would leave the contract without any Admin",
+            Cep18Error::NotPendingAdmin => "The caller is not the account proposed as Admin",
+            Cep18Error::InvalidAdminCount => "The number of Admin accounts provided is invalid",
+            Cep18Error::RoleMemberIndexOutOfRange => {
+                "There is no member of the badge at the requested index"
+            }
+            Cep18Error::ProposalRequired => {
+                "The action has to be proposed and approved by enough Admin accounts"
+            }
+            Cep18Error::InvalidProposalThreshold => {
+                "The proposal threshold is zero or above the number of Admin accounts"
+            }
+            Cep18Error::ProposalNotFound => {
+                "The proposal id does not refer to a proposal that was made"
+            }
+            Cep18Error::ProposalAlreadyExecuted => "The proposal was already carried out",
+            Cep18Error::ProposalAlreadyApproved => "The caller already approved the proposal",
+            Cep18Error::InvalidProposalAction => {
+                "The proposed action is not one of the supported actions"
+            }
+            Cep18Error::TimelockRequired => {
+                "The action has to be scheduled and executed once the timelock delay has passed"
+            }
+            Cep18Error::OperationNotFound => {
+                "The operation id does not refer to an operation that was scheduled"
+            }
+            Cep18Error::OperationNotPending => "The operation was already executed or cancelled",
+            Cep18Error::OperationNotReady => {
+                "The timelock delay of the operation has not passed yet"
+            }
+            Cep18Error::UpgradeNotScheduled => {
+                "The upgrade was not scheduled and executed through the timelock"
+            }
+            Cep18Error::InvalidTimelockDelay => "The timelock delay provided is invalid",
+            Cep18Error::MinterAllowanceExceeded => {
+                "The minter has not enough allowance left to mint the amount"
+            }
+            Cep18Error::RecipientNotContract => {
+                "The recipient of a transfer with a call is not a contract package"
+            }
+            Cep18Error::TransferRejected => {
+                "The recipient contract rejected the tokens it received"
+            }
+            Cep18Error::AdminGrantNotAllowed => {
+                "Admins are only added through propose_admin and accept_admin"
+            }
+            Cep18Error::ProposalCancelled => "The proposal was cancelled",
+            Cep18Error::NotProposer => "Only the Admin that created the proposal can cancel it",
+        };
+        write!(formatter, "{} ({})", message, *self as u16)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Cep18Error {}
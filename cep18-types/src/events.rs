@@ -0,0 +1,366 @@
+//! Events recorded by the CEP-18 contract following the Casper Event Standard.
+use alloc::{collections::BTreeMap, string::String};
+
+use casper_event_standard::{Event, Schemas};
+use casper_types::{
+    bytesrepr::{self, FromBytes},
+    Key, U256,
+};
+
+use crate::SecurityBadge;
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Mint {
+    pub recipient: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Burn {
+    pub owner: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct BurnFrom {
+    pub spender: Key,
+    pub owner: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct SetAllowance {
+    pub owner: Key,
+    pub spender: Key,
+    pub allowance: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct IncreaseAllowance {
+    pub owner: Key,
+    pub spender: Key,
+    pub allowance: U256,
+    pub inc_by: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct DecreaseAllowance {
+    pub owner: Key,
+    pub spender: Key,
+    pub allowance: U256,
+    pub decr_by: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Transfer {
+    pub sender: Key,
+    pub recipient: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct TransferFrom {
+    pub spender: Key,
+    pub owner: Key,
+    pub recipient: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ChangeSecurity {
+    pub admin: Key,
+    pub sec_change_map: BTreeMap<Key, SecurityBadge>,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ProposeAdmin {
+    pub admin: Key,
+    pub candidate: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AcceptAdmin {
+    pub admin: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct CreateProposal {
+    pub admin: Key,
+    pub id: u64,
+    pub kind: u8,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ApproveProposal {
+    pub admin: Key,
+    pub id: u64,
+    pub approvals: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ExecuteProposal {
+    pub admin: Key,
+    pub id: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct CancelProposal {
+    pub admin: Key,
+    pub id: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ScheduleOperation {
+    pub admin: Key,
+    pub id: u64,
+    pub kind: u8,
+    pub ready_at: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ExecuteOperation {
+    pub admin: Key,
+    pub id: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct CancelOperation {
+    pub admin: Key,
+    pub id: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct SetMinterAllowance {
+    pub admin: Key,
+    pub minter: Key,
+    pub allowance: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Paused {
+    pub admin: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Unpaused {
+    pub admin: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    pub admin: Key,
+    pub id: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct DelegateChanged {
+    pub delegator: Key,
+    pub from_delegate: Option<Key>,
+    pub to_delegate: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct DelegateVotesChanged {
+    pub delegate: Key,
+    pub previous_votes: U256,
+    pub new_votes: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct FreezeAccount {
+    pub admin: Key,
+    pub account: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct UnfreezeAccount {
+    pub admin: Key,
+    pub account: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AddToWhitelist {
+    pub admin: Key,
+    pub account: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct RemoveFromWhitelist {
+    pub admin: Key,
+    pub account: Key,
+}
+
+/// Prefix the Casper Event Standard adds to the name of an event when serializing it.
+const EVENT_PREFIX: &str = "event_";
+
+/// Any event recorded by the CEP-18 contract.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Cep18Event {
+    Mint(Mint),
+    Burn(Burn),
+    BurnFrom(BurnFrom),
+    SetAllowance(SetAllowance),
+    IncreaseAllowance(IncreaseAllowance),
+    DecreaseAllowance(DecreaseAllowance),
+    Transfer(Transfer),
+    TransferFrom(TransferFrom),
+    ChangeSecurity(ChangeSecurity),
+    ProposeAdmin(ProposeAdmin),
+    AcceptAdmin(AcceptAdmin),
+    CreateProposal(CreateProposal),
+    ApproveProposal(ApproveProposal),
+    ExecuteProposal(ExecuteProposal),
+    CancelProposal(CancelProposal),
+    ScheduleOperation(ScheduleOperation),
+    ExecuteOperation(ExecuteOperation),
+    CancelOperation(CancelOperation),
+    SetMinterAllowance(SetMinterAllowance),
+    Paused(Paused),
+    Unpaused(Unpaused),
+    Snapshot(Snapshot),
+    DelegateChanged(DelegateChanged),
+    DelegateVotesChanged(DelegateVotesChanged),
+    FreezeAccount(FreezeAccount),
+    UnfreezeAccount(UnfreezeAccount),
+    AddToWhitelist(AddToWhitelist),
+    RemoveFromWhitelist(RemoveFromWhitelist),
+}
+
+impl Cep18Event {
+    /// Name of the event, as listed in the stored schemas.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Cep18Event::Mint(_) => "Mint",
+            Cep18Event::Burn(_) => "Burn",
+            Cep18Event::BurnFrom(_) => "BurnFrom",
+            Cep18Event::SetAllowance(_) => "SetAllowance",
+            Cep18Event::IncreaseAllowance(_) => "IncreaseAllowance",
+            Cep18Event::DecreaseAllowance(_) => "DecreaseAllowance",
+            Cep18Event::Transfer(_) => "Transfer",
+            Cep18Event::TransferFrom(_) => "TransferFrom",
+            Cep18Event::ChangeSecurity(_) => "ChangeSecurity",
+            Cep18Event::ProposeAdmin(_) => "ProposeAdmin",
+            Cep18Event::AcceptAdmin(_) => "AcceptAdmin",
+            Cep18Event::CreateProposal(_) => "CreateProposal",
+            Cep18Event::ApproveProposal(_) => "ApproveProposal",
+            Cep18Event::ExecuteProposal(_) => "ExecuteProposal",
+            Cep18Event::CancelProposal(_) => "CancelProposal",
+            Cep18Event::ScheduleOperation(_) => "ScheduleOperation",
+            Cep18Event::ExecuteOperation(_) => "ExecuteOperation",
+            Cep18Event::CancelOperation(_) => "CancelOperation",
+            Cep18Event::SetMinterAllowance(_) => "SetMinterAllowance",
+            Cep18Event::Paused(_) => "Paused",
+            Cep18Event::Unpaused(_) => "Unpaused",
+            Cep18Event::Snapshot(_) => "Snapshot",
+            Cep18Event::DelegateChanged(_) => "DelegateChanged",
+            Cep18Event::DelegateVotesChanged(_) => "DelegateVotesChanged",
+            Cep18Event::FreezeAccount(_) => "FreezeAccount",
+            Cep18Event::UnfreezeAccount(_) => "UnfreezeAccount",
+            Cep18Event::AddToWhitelist(_) => "AddToWhitelist",
+            Cep18Event::RemoveFromWhitelist(_) => "RemoveFromWhitelist",
+        }
+    }
+}
+
+/// Decodes an event as stored in the `__events` dictionary, picking the variant from the event
+/// name written first.
+impl FromBytes for Cep18Event {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (prefixed_name, _) = String::from_bytes(bytes)?;
+        let name = prefixed_name
+            .strip_prefix(EVENT_PREFIX)
+            .ok_or(bytesrepr::Error::Formatting)?;
+        match name {
+            "Mint" => Mint::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::Mint(event), remainder)),
+            "Burn" => Burn::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::Burn(event), remainder)),
+            "BurnFrom" => BurnFrom::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::BurnFrom(event), remainder)),
+            "SetAllowance" => SetAllowance::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::SetAllowance(event), remainder)),
+            "IncreaseAllowance" => IncreaseAllowance::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::IncreaseAllowance(event), remainder)),
+            "DecreaseAllowance" => DecreaseAllowance::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::DecreaseAllowance(event), remainder)),
+            "Transfer" => Transfer::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::Transfer(event), remainder)),
+            "TransferFrom" => TransferFrom::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::TransferFrom(event), remainder)),
+            "ChangeSecurity" => ChangeSecurity::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::ChangeSecurity(event), remainder)),
+            "ProposeAdmin" => ProposeAdmin::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::ProposeAdmin(event), remainder)),
+            "AcceptAdmin" => AcceptAdmin::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::AcceptAdmin(event), remainder)),
+            "CreateProposal" => CreateProposal::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::CreateProposal(event), remainder)),
+            "ApproveProposal" => ApproveProposal::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::ApproveProposal(event), remainder)),
+            "ExecuteProposal" => ExecuteProposal::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::ExecuteProposal(event), remainder)),
+            "CancelProposal" => CancelProposal::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::CancelProposal(event), remainder)),
+            "ScheduleOperation" => ScheduleOperation::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::ScheduleOperation(event), remainder)),
+            "ExecuteOperation" => ExecuteOperation::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::ExecuteOperation(event), remainder)),
+            "CancelOperation" => CancelOperation::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::CancelOperation(event), remainder)),
+            "SetMinterAllowance" => SetMinterAllowance::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::SetMinterAllowance(event), remainder)),
+            "Paused" => Paused::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::Paused(event), remainder)),
+            "Unpaused" => Unpaused::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::Unpaused(event), remainder)),
+            "Snapshot" => Snapshot::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::Snapshot(event), remainder)),
+            "DelegateChanged" => DelegateChanged::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::DelegateChanged(event), remainder)),
+            "DelegateVotesChanged" => DelegateVotesChanged::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::DelegateVotesChanged(event), remainder)),
+            "FreezeAccount" => FreezeAccount::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::FreezeAccount(event), remainder)),
+            "UnfreezeAccount" => UnfreezeAccount::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::UnfreezeAccount(event), remainder)),
+            "AddToWhitelist" => AddToWhitelist::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::AddToWhitelist(event), remainder)),
+            "RemoveFromWhitelist" => RemoveFromWhitelist::from_bytes(bytes)
+                .map(|(event, remainder)| (Cep18Event::RemoveFromWhitelist(event), remainder)),
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// Schemas of every event, stored under `__events_schema`.
+pub fn schemas() -> Schemas {
+    Schemas::new()
+        .with::<Mint>()
+        .with::<Burn>()
+        .with::<BurnFrom>()
+        .with::<SetAllowance>()
+        .with::<IncreaseAllowance>()
+        .with::<DecreaseAllowance>()
+        .with::<Transfer>()
+        .with::<TransferFrom>()
+        .with::<ChangeSecurity>()
+        .with::<ProposeAdmin>()
+        .with::<AcceptAdmin>()
+        .with::<CreateProposal>()
+        .with::<ApproveProposal>()
+        .with::<ExecuteProposal>()
+        .with::<CancelProposal>()
+        .with::<ScheduleOperation>()
+        .with::<ExecuteOperation>()
+        .with::<CancelOperation>()
+        .with::<SetMinterAllowance>()
+        .with::<Paused>()
+        .with::<Unpaused>()
+        .with::<Snapshot>()
+        .with::<DelegateChanged>()
+        .with::<DelegateVotesChanged>()
+        .with::<FreezeAccount>()
+        .with::<UnfreezeAccount>()
+        .with::<AddToWhitelist>()
+        .with::<RemoveFromWhitelist>()
+}
@@ -0,0 +1,178 @@
+//! Reconstruction of balances, allowances and total supply from the events of a contract.
+use alloc::collections::BTreeMap;
+
+use casper_types::{Key, U256};
+
+use crate::{events::Cep18Event, Cep18Error};
+
+/// Balances, allowances and total supply obtained by replaying events, to check an off-chain
+/// ledger against the contract.
+///
+/// Allowances are the amounts last written by the contract, whether they expired or not, as the
+/// events do not carry expiries.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ledger {
+    balances: BTreeMap<Key, U256>,
+    allowances: BTreeMap<(Key, Key), U256>,
+    total_supply: U256,
+}
+
+impl Ledger {
+    /// Ledger of a freshly installed contract. The initial supply credited to the installer is not
+    /// recorded as an event.
+    pub fn new(installer: Key, initial_supply: U256) -> Self {
+        let mut ledger = Ledger {
+            total_supply: initial_supply,
+            ..Default::default()
+        };
+        ledger.set_balance(installer, initial_supply);
+        ledger
+    }
+
+    pub fn balance_of(&self, owner: Key) -> U256 {
+        self.balances.get(&owner).copied().unwrap_or_default()
+    }
+
+    pub fn allowance(&self, owner: Key, spender: Key) -> U256 {
+        self.allowances
+            .get(&(owner, spender))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn total_supply(&self) -> U256 {
+        self.total_supply
+    }
+
+    /// Accounts holding tokens, with their balance.
+    pub fn balances(&self) -> &BTreeMap<Key, U256> {
+        &self.balances
+    }
+
+    /// Non-zero allowances, by owner and spender.
+    pub fn allowances(&self) -> &BTreeMap<(Key, Key), U256> {
+        &self.allowances
+    }
+
+    /// Applies the events in order, stopping at the first one inconsistent with the ledger.
+    pub fn replay<'a, I>(&mut self, events: I) -> Result<(), Cep18Error>
+    where
+        I: IntoIterator<Item = &'a Cep18Event>,
+    {
+        events.into_iter().try_for_each(|event| self.apply(event))
+    }
+
+    /// Applies the changes an event records. Events that do not move tokens or allowances are
+    /// ignored.
+    pub fn apply(&mut self, event: &Cep18Event) -> Result<(), Cep18Error> {
+        match event {
+            Cep18Event::Mint(mint) => {
+                self.total_supply = self
+                    .total_supply
+                    .checked_add(mint.amount)
+                    .ok_or(Cep18Error::Overflow)?;
+                self.credit(mint.recipient, mint.amount)
+            }
+            Cep18Event::Burn(burn) => self.burn(burn.owner, burn.amount),
+            Cep18Event::BurnFrom(burn_from) => {
+                self.spend_allowance(burn_from.owner, burn_from.spender, burn_from.amount)?;
+                self.burn(burn_from.owner, burn_from.amount)
+            }
+            Cep18Event::SetAllowance(set_allowance) => {
+                self.set_allowance(
+                    set_allowance.owner,
+                    set_allowance.spender,
+                    set_allowance.allowance,
+                );
+                Ok(())
+            }
+            Cep18Event::IncreaseAllowance(increase_allowance) => {
+                self.set_allowance(
+                    increase_allowance.owner,
+                    increase_allowance.spender,
+                    increase_allowance.allowance,
+                );
+                Ok(())
+            }
+            Cep18Event::DecreaseAllowance(decrease_allowance) => {
+                self.set_allowance(
+                    decrease_allowance.owner,
+                    decrease_allowance.spender,
+                    decrease_allowance.allowance,
+                );
+                Ok(())
+            }
+            Cep18Event::Transfer(transfer) => {
+                self.debit(transfer.sender, transfer.amount)?;
+                self.credit(transfer.recipient, transfer.amount)
+            }
+            Cep18Event::TransferFrom(transfer_from) => {
+                self.spend_allowance(
+                    transfer_from.owner,
+                    transfer_from.spender,
+                    transfer_from.amount,
+                )?;
+                self.debit(transfer_from.owner, transfer_from.amount)?;
+                self.credit(transfer_from.recipient, transfer_from.amount)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn burn(&mut self, owner: Key, amount: U256) -> Result<(), Cep18Error> {
+        self.debit(owner, amount)?;
+        self.total_supply = self
+            .total_supply
+            .checked_sub(amount)
+            .ok_or(Cep18Error::Overflow)?;
+        Ok(())
+    }
+
+    fn credit(&mut self, owner: Key, amount: U256) -> Result<(), Cep18Error> {
+        let balance = self
+            .balance_of(owner)
+            .checked_add(amount)
+            .ok_or(Cep18Error::Overflow)?;
+        self.set_balance(owner, balance);
+        Ok(())
+    }
+
+    fn debit(&mut self, owner: Key, amount: U256) -> Result<(), Cep18Error> {
+        let balance = self
+            .balance_of(owner)
+            .checked_sub(amount)
+            .ok_or(Cep18Error::InsufficientBalance)?;
+        self.set_balance(owner, balance);
+        Ok(())
+    }
+
+    fn spend_allowance(
+        &mut self,
+        owner: Key,
+        spender: Key,
+        amount: U256,
+    ) -> Result<(), Cep18Error> {
+        let allowance = self
+            .allowance(owner, spender)
+            .checked_sub(amount)
+            .ok_or(Cep18Error::InsufficientAllowance)?;
+        self.set_allowance(owner, spender, allowance);
+        Ok(())
+    }
+
+    fn set_balance(&mut self, owner: Key, balance: U256) {
+        if balance.is_zero() {
+            self.balances.remove(&owner);
+        } else {
+            self.balances.insert(owner, balance);
+        }
+    }
+
+    fn set_allowance(&mut self, owner: Key, spender: Key, allowance: U256) {
+        if allowance.is_zero() {
+            self.allowances.remove(&(owner, spender));
+        } else {
+            self.allowances.insert((owner, spender), allowance);
+        }
+    }
+}
@@ -0,0 +1,23 @@
+//! Types shared by the CEP-18 contract and the code consuming it off-chain, such as indexers
+//! decoding its events or tests matching its errors, along with the names of its entry points,
+//! arguments and named keys.
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod constants;
+pub mod error;
+pub mod events;
+pub mod ledger;
+pub mod modes;
+pub mod reader;
+mod security_badge;
+
+pub use error::Cep18Error;
+pub use events::Cep18Event;
+pub use ledger::Ledger;
+pub use modes::{EventsMode, TransferFilterMode};
+pub use reader::{read_events, EventSource, ReadError};
+pub use security_badge::SecurityBadge;
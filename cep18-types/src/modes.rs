@@ -0,0 +1,45 @@
+//! Modes set at install, stored as `u8` under the named keys of the same name.
+use core::convert::TryFrom;
+
+use crate::Cep18Error;
+
+/// How the token records its events.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum EventsMode {
+    NoEvents = 0,
+    CES = 1,
+}
+
+impl TryFrom<u8> for EventsMode {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(EventsMode::NoEvents),
+            1 => Ok(EventsMode::CES),
+            _ => Err(Cep18Error::InvalidEventsMode),
+        }
+    }
+}
+
+/// Which accounts the token lets send and receive tokens.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferFilterMode {
+    None = 0,
+    Allowlist = 1,
+}
+
+impl TryFrom<u8> for TransferFilterMode {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TransferFilterMode::None),
+            1 => Ok(TransferFilterMode::Allowlist),
+            _ => Err(Cep18Error::InvalidTransferFilterMode),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Reading the events a CEP-18 contract recorded following the Casper Event Standard.
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt::{self, Debug, Display, Formatter};
+
+use casper_types::{
+    bytesrepr::{self, Bytes, ToBytes},
+    CLType, CLValue, CLValueError,
+};
+
+use crate::events::{schemas, Cep18Event};
+
+/// Fields of the schema of an event, in the order they are serialized.
+type SchemaFields = Vec<(String, CLType)>;
+
+/// Read access to the named keys `init_events` creates under the contract, backed for instance by
+/// queries to a node or by a test builder.
+pub trait EventSource {
+    type Error;
+
+    /// Value stored under the `__events_schema` named key.
+    fn events_schema(&self) -> Result<CLValue, Self::Error>;
+
+    /// Value stored under the `__events_length` named key.
+    fn events_length(&self) -> Result<CLValue, Self::Error>;
+
+    /// Item stored under `index` in the `__events` dictionary.
+    fn event(&self, index: u32) -> Result<CLValue, Self::Error>;
+}
+
+/// Failure to read the events of a contract.
+#[derive(Debug)]
+pub enum ReadError<E> {
+    /// The source failed to provide a value.
+    Source(E),
+    /// A value provided by the source does not have the expected type.
+    CLValue(CLValueError),
+    /// The stored schemas list an event this crate does not know, likely recorded by a newer
+    /// version of the contract.
+    UnknownEvent(String),
+    /// The stored schema of this event differs from the one of this crate.
+    SchemaMismatch(String),
+    /// The event at `index` could not be decoded.
+    InvalidEvent { index: u32, error: bytesrepr::Error },
+}
+
+impl<E: Display> Display for ReadError<E> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            ReadError::Source(error) => write!(formatter, "failed to read from source: {}", error),
+            ReadError::CLValue(error) => write!(formatter, "unexpected value: {}", error),
+            ReadError::UnknownEvent(name) => write!(formatter, "unknown event {}", name),
+            ReadError::SchemaMismatch(name) => {
+                write!(formatter, "schema of event {} does not match", name)
+            }
+            ReadError::InvalidEvent { index, error } => {
+                write!(formatter, "failed to decode event {}: {}", index, error)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Debug + Display> std::error::Error for ReadError<E> {}
+
+/// Checks the schemas stored under `__events_schema` against the ones of this crate. Schemas
+/// stored by an older version of the contract may lack the events introduced since.
+pub fn check_schemas<E>(stored_schemas: &CLValue) -> Result<(), ReadError<E>> {
+    let stored_schemas = schema_fields(stored_schemas.inner_bytes().to_vec())?;
+    let known_schemas = schema_fields(
+        schemas()
+            .to_bytes()
+            .map_err(|error| ReadError::CLValue(CLValueError::Serialization(error)))?,
+    )?;
+    for (name, fields) in stored_schemas {
+        match known_schemas.get(&name) {
+            None => return Err(ReadError::UnknownEvent(name)),
+            Some(known_fields) if *known_fields != fields => {
+                return Err(ReadError::SchemaMismatch(name))
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(())
+}
+
+/// Reads the events recorded from `start` on, once the stored schemas are checked. Indexers pass
+/// the number of events they already processed as `start`.
+pub fn read_events<S: EventSource>(
+    source: &S,
+    start: u32,
+) -> Result<Vec<Cep18Event>, ReadError<S::Error>> {
+    check_schemas(&source.events_schema().map_err(ReadError::Source)?)?;
+    let events_length: u32 = source
+        .events_length()
+        .map_err(ReadError::Source)?
+        .into_t()
+        .map_err(ReadError::CLValue)?;
+    (start..events_length)
+        .map(|index| {
+            let event_bytes: Bytes = source
+                .event(index)
+                .map_err(ReadError::Source)?
+                .into_t()
+                .map_err(ReadError::CLValue)?;
+            bytesrepr::deserialize(event_bytes.to_vec())
+                .map_err(|error| ReadError::InvalidEvent { index, error })
+        })
+        .collect()
+}
+
+fn schema_fields<E>(bytes: Vec<u8>) -> Result<BTreeMap<String, SchemaFields>, ReadError<E>> {
+    bytesrepr::deserialize(bytes)
+        .map_err(|error| ReadError::CLValue(CLValueError::Serialization(error)))
+}
@@ -0,0 +1,53 @@
+//! Security badges granting access to restricted entry points.
+use alloc::{vec, vec::Vec};
+
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped,
+};
+
+/// Access group a badge holder belongs to.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SecurityBadge {
+    Admin = 0,
+    Minter = 1,
+    None = 2,
+    Burner = 3,
+    MintAndBurn = 4,
+    KycAdmin = 5,
+}
+
+impl CLTyped for SecurityBadge {
+    fn cl_type() -> CLType {
+        CLType::U8
+    }
+}
+
+impl ToBytes for SecurityBadge {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        Ok(vec![*self as u8])
+    }
+
+    fn serialized_length(&self) -> usize {
+        1
+    }
+}
+
+impl FromBytes for SecurityBadge {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        Ok((
+            match tag {
+                0 => SecurityBadge::Admin,
+                1 => SecurityBadge::Minter,
+                2 => SecurityBadge::None,
+                3 => SecurityBadge::Burner,
+                4 => SecurityBadge::MintAndBurn,
+                5 => SecurityBadge::KycAdmin,
+                _ => return Err(bytesrepr::Error::Formatting),
+            },
+            remainder,
+        ))
+    }
+}
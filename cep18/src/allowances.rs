@@ -7,13 +7,21 @@ use casper_contract::{
 };
 use casper_types::{bytesrepr::ToBytes, Key, URef, U256};
 
-use crate::{constants::ALLOWANCES, utils};
+use crate::{
+    constants::{ALLOWANCES, ALLOWANCE_EXPIRATIONS, EXPIRES_AT},
+    utils, Cep18Error,
+};
 
 #[inline]
 pub(crate) fn get_allowances_uref() -> URef {
     utils::get_uref(ALLOWANCES)
 }
 
+#[inline]
+pub(crate) fn get_allowance_expirations_uref() -> URef {
+    utils::get_uref(ALLOWANCE_EXPIRATIONS)
+}
+
 /// Creates a dictionary item key for an (owner, spender) pair.
 pub(crate) fn make_dictionary_item_key(owner: Key, spender: Key) -> String {
     let mut preimage = Vec::new();
@@ -30,10 +38,67 @@ pub(crate) fn write_allowance_to(allowance_uref: URef, owner: Key, spender: Key,
     storage::dictionary_put(allowance_uref, &dictionary_item_key, amount)
 }
 
-/// Reads an allowance for a owner and spender
+/// Reads an allowance for a owner and spender, an expired allowance being worth zero.
 pub(crate) fn read_allowance_from(allowances_uref: URef, owner: Key, spender: Key) -> U256 {
+    let expires_at =
+        read_allowance_expiration_from(get_allowance_expirations_uref(), owner, spender);
+    if expires_at.map_or(false, has_expired) {
+        return U256::zero();
+    }
     let dictionary_item_key = make_dictionary_item_key(owner, spender);
     storage::dictionary_get(allowances_uref, &dictionary_item_key)
         .unwrap_or_revert()
         .unwrap_or_default()
 }
+
+/// Writes the block time after which the allowance of owner and spender expires, `None` meaning
+/// the allowance never expires.
+pub(crate) fn write_allowance_expiration_to(
+    expirations_uref: URef,
+    owner: Key,
+    spender: Key,
+    expires_at: Option<u64>,
+) {
+    let dictionary_item_key = make_dictionary_item_key(owner, spender);
+    storage::dictionary_put(expirations_uref, &dictionary_item_key, expires_at)
+}
+
+/// Reads the block time after which the allowance of owner and spender expires, if any.
+pub(crate) fn read_allowance_expiration_from(
+    expirations_uref: URef,
+    owner: Key,
+    spender: Key,
+) -> Option<u64> {
+    let dictionary_item_key = make_dictionary_item_key(owner, spender);
+    storage::dictionary_get(expirations_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .flatten()
+}
+
+/// Returns whether an allowance expiring at `expires_at` is no longer usable.
+pub(crate) fn has_expired(expires_at: u64) -> bool {
+    u64::from(runtime::get_blocktime()) > expires_at
+}
+
+/// Reads the optional `expires_at` runtime argument, which must not already be in the past.
+pub(crate) fn get_optional_expires_at_arg() -> Option<u64> {
+    let expires_at: Option<u64> = utils::get_optional_named_arg_with_user_errors(
+        EXPIRES_AT,
+        Cep18Error::InvalidAllowanceExpiry,
+    );
+    if expires_at.map_or(false, has_expired) {
+        runtime::revert(Cep18Error::InvalidAllowanceExpiry);
+    }
+    expires_at
+}
+
+/// Deducts `amount` from the allowance `owner` granted to `spender`.
+pub(crate) fn spend_allowance(owner: Key, spender: Key, amount: U256) -> Result<(), Cep18Error> {
+    let allowances_uref = get_allowances_uref();
+    let new_allowance = read_allowance_from(allowances_uref, owner, spender)
+        .checked_sub(amount)
+        .ok_or(Cep18Error::InsufficientAllowance)?;
+    write_allowance_to(allowances_uref, owner, spender, new_allowance);
+
+    Ok(())
+}
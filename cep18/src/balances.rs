@@ -4,12 +4,17 @@ use alloc::string::String;
 use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
 use casper_types::{bytesrepr::ToBytes, Key, URef, U256};
 
-use crate::{constants::BALANCES, error::Cep18Error, utils};
+use crate::{
+    constants::{BALANCES, MAX_SUPPLY},
+    holders, snapshots,
+    utils::{self, get_total_supply_uref, read_total_supply_from, write_total_supply_to},
+    votes, whitelist, Cep18Error,
+};
 
 /// Creates a dictionary item key for a dictionary item, by base64 encoding the Key argument
 /// since stringified Keys are too long to be used as dictionary keys.
 #[inline]
-fn make_dictionary_item_key(owner: Key) -> String {
+pub(crate) fn make_dictionary_item_key(owner: Key) -> String {
     let preimage = owner.to_bytes().unwrap_or_revert();
     // NOTE: As for now dictionary item keys are limited to 64 characters only. Instead of using
     // hashing (which will effectively hash a hash) we'll use base64. Preimage is 33 bytes for
@@ -20,15 +25,28 @@ fn make_dictionary_item_key(owner: Key) -> String {
     base64::encode(preimage)
 }
 
+/// Same as [`make_dictionary_item_key`] for the item at `index` of a list kept per `owner`, the 41
+/// bytes preimage being encoded into 56 characters.
+#[inline]
+pub(crate) fn make_indexed_item_key(owner: Key, index: u64) -> String {
+    let preimage = (owner, index).to_bytes().unwrap_or_revert();
+    base64::encode(preimage)
+}
+
 /// Getter for the "balances" dictionary URef.
 pub(crate) fn get_balances_uref() -> URef {
     utils::get_uref(BALANCES)
 }
 
-/// Writes token balance of a specified account into a dictionary.
+/// Writes token balance of a specified account into a dictionary, keeping the holder index,
+/// snapshots and voting power in sync.
 pub(crate) fn write_balance_to(balances_uref: URef, address: Key, amount: U256) {
+    let read_previous_balance = || read_balance_from(balances_uref, address);
+    snapshots::update_balance_snapshot(address, read_previous_balance);
+    votes::move_voting_power(address, read_previous_balance, amount);
     let dictionary_item_key = make_dictionary_item_key(address);
     storage::dictionary_put(balances_uref, &dictionary_item_key, amount);
+    holders::update_holder_index(address, amount);
 }
 
 /// Reads token balance of a specified account.
@@ -54,6 +72,7 @@ pub(crate) fn transfer_balance(
     if sender == recipient || amount.is_zero() {
         return Ok(());
     }
+    whitelist::check_recipient(recipient)?;
 
     let balances_uref = get_balances_uref();
     let new_sender_balance = {
@@ -75,3 +94,107 @@ pub(crate) fn transfer_balance(
 
     Ok(())
 }
+
+/// Transfer tokens from the `sender` to every recipient of `transfers`, debiting the sender once
+/// for the total amount.
+///
+/// This function should not be used directly by contract's entrypoint as it does not validate the
+/// sender.
+pub(crate) fn batch_transfer_balance(
+    sender: Key,
+    transfers: &[(Key, U256)],
+) -> Result<(), Cep18Error> {
+    let total_amount = transfers
+        .iter()
+        .try_fold(U256::zero(), |total, (_, amount)| {
+            total.checked_add(*amount)
+        })
+        .ok_or(Cep18Error::Overflow)?;
+
+    let balances_uref = get_balances_uref();
+    let new_sender_balance = {
+        let sender_balance = read_balance_from(balances_uref, sender);
+        sender_balance
+            .checked_sub(total_amount)
+            .ok_or(Cep18Error::InsufficientBalance)?
+    };
+    write_balance_to(balances_uref, sender, new_sender_balance);
+
+    for &(recipient, amount) in transfers {
+        if amount.is_zero() {
+            continue;
+        }
+        whitelist::check_recipient(recipient)?;
+        let new_recipient_balance = {
+            let recipient_balance = read_balance_from(balances_uref, recipient);
+            recipient_balance
+                .checked_add(amount)
+                .ok_or(Cep18Error::Overflow)?
+        };
+        write_balance_to(balances_uref, recipient, new_recipient_balance);
+    }
+
+    Ok(())
+}
+
+/// Mints tokens to every recipient of `mints`, raising the total supply once for the total amount.
+///
+/// This function should not be used directly by contract's entrypoint as it does not validate the
+/// minter.
+pub(crate) fn mint_balances(mints: &[(Key, U256)]) -> Result<(), Cep18Error> {
+    let total_amount = mints
+        .iter()
+        .try_fold(U256::zero(), |total, (_, amount)| {
+            total.checked_add(*amount)
+        })
+        .ok_or(Cep18Error::Overflow)?;
+
+    let total_supply_uref = get_total_supply_uref();
+    let new_total_supply = {
+        let total_supply = read_total_supply_from(total_supply_uref);
+        total_supply
+            .checked_add(total_amount)
+            .ok_or(Cep18Error::Overflow)?
+    };
+    if new_total_supply > utils::read_from::<U256>(MAX_SUPPLY) {
+        return Err(Cep18Error::ExceedsMaxSupply);
+    }
+
+    let balances_uref = get_balances_uref();
+    for &(owner, amount) in mints {
+        whitelist::check_recipient(owner)?;
+        let new_balance = {
+            let balance = read_balance_from(balances_uref, owner);
+            balance.checked_add(amount).ok_or(Cep18Error::Overflow)?
+        };
+        write_balance_to(balances_uref, owner, new_balance);
+    }
+    write_total_supply_to(total_supply_uref, new_total_supply);
+
+    Ok(())
+}
+
+/// Burns tokens of the `owner`, reducing the total supply accordingly.
+///
+/// This function should not be used directly by contract's entrypoint as it does not validate the
+/// owner.
+pub(crate) fn burn_balance(owner: Key, amount: U256) -> Result<(), Cep18Error> {
+    let balances_uref = get_balances_uref();
+    let total_supply_uref = get_total_supply_uref();
+    let new_balance = {
+        let balance = read_balance_from(balances_uref, owner);
+        balance
+            .checked_sub(amount)
+            .ok_or(Cep18Error::InsufficientBalance)?
+    };
+    let new_total_supply = {
+        let total_supply = read_total_supply_from(total_supply_uref);
+        total_supply
+            .checked_sub(amount)
+            .ok_or(Cep18Error::Overflow)?
+    };
+    write_balance_to(balances_uref, owner, new_balance);
+    write_total_supply_to(total_supply_uref, new_total_supply);
+
+    Ok(())
+}
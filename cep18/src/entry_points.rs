@@ -2,18 +2,37 @@
 use alloc::{string::String, vec, vec::Vec};
 
 use casper_types::{
-    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter,
-    U256,
+    bytesrepr::Bytes, CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints,
+    Key, Parameter, PublicKey, U256,
 };
 
 use crate::constants::{
-    ADDRESS, ALLOWANCE_ENTRY_POINT_NAME, AMOUNT, APPROVE_ENTRY_POINT_NAME,
-    BALANCE_OF_ENTRY_POINT_NAME, BURN_ENTRY_POINT_NAME, CHANGE_SECURITY_ENTRY_POINT_NAME,
-    DECIMALS_ENTRY_POINT_NAME, DECREASE_ALLOWANCE_ENTRY_POINT_NAME,
-    INCREASE_ALLOWANCE_ENTRY_POINT_NAME, INIT_ENTRY_POINT_NAME, MINT_ENTRY_POINT_NAME,
-    NAME_ENTRY_POINT_NAME, OWNER, RECIPIENT, SPENDER, SYMBOL_ENTRY_POINT_NAME,
-    TOTAL_SUPPLY_ENTRY_POINT_NAME, TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
+    ACCEPT_ADMIN_ENTRY_POINT_NAME, ACCOUNT, ACCOUNTS, ACTION, ADDRESS,
+    ADD_TO_WHITELIST_ENTRY_POINT_NAME, ALLOWANCE_ENTRY_POINT_NAME, AMOUNT, AMOUNTS,
+    APPROVE_ENTRY_POINT_NAME, APPROVE_PROPOSAL_ENTRY_POINT_NAME, BADGE,
+    BALANCE_OF_AT_ENTRY_POINT_NAME, BALANCE_OF_ENTRY_POINT_NAME, BATCH_MINT_ENTRY_POINT_NAME,
+    BATCH_TRANSFER_ENTRY_POINT_NAME, BURN_ENTRY_POINT_NAME, BURN_FROM_ENTRY_POINT_NAME,
+    CANCEL_ENTRY_POINT_NAME, CANCEL_PROPOSAL_ENTRY_POINT_NAME, CHANGE_SECURITY_ENTRY_POINT_NAME,
+    DATA, DEADLINE, DECIMALS_ENTRY_POINT_NAME, DECREASE_ALLOWANCE_ENTRY_POINT_NAME, DELEGATEE,
+    DELEGATES_ENTRY_POINT_NAME, DELEGATE_ENTRY_POINT_NAME, EXECUTE_ENTRY_POINT_NAME,
+    FREEZE_ACCOUNT_ENTRY_POINT_NAME, FROM, GET_BADGE_ENTRY_POINT_NAME,
+    GET_PAST_VOTES_ENTRY_POINT_NAME, GET_VOTES_ENTRY_POINT_NAME, HOLDER_AT_ENTRY_POINT_NAME,
+    HOLDER_COUNT_ENTRY_POINT_NAME, INCREASE_ALLOWANCE_ENTRY_POINT_NAME, INDEX,
+    INDEX_ACCOUNTS_ENTRY_POINT_NAME, INIT_ENTRY_POINT_NAME, IS_ADMIN_ENTRY_POINT_NAME,
+    IS_MINTER_ENTRY_POINT_NAME, MAX_SUPPLY_ENTRY_POINT_NAME, MIGRATE_ENTRY_POINT_NAME,
+    MINTER_ALLOWANCE_ENTRY_POINT_NAME, MINT_ENTRY_POINT_NAME, NAME_ENTRY_POINT_NAME, NONCE,
+    NONCE_OF_ENTRY_POINT_NAME, ON_CEP18_RECEIVED_ENTRY_POINT_NAME, OPERATION_ID, OPERATOR, OWNER,
+    OWNER_PUBLIC_KEY, PAUSE_ENTRY_POINT_NAME, PERMIT_ENTRY_POINT_NAME, PROPOSAL_ID,
+    PROPOSE_ADMIN_ENTRY_POINT_NAME, PROPOSE_ENTRY_POINT_NAME, RECIPIENT, RECIPIENTS,
+    REMOVE_FROM_WHITELIST_ENTRY_POINT_NAME, ROLE_MEMBER_AT_ENTRY_POINT_NAME,
+    ROLE_MEMBER_COUNT_ENTRY_POINT_NAME, SCHEDULE_ENTRY_POINT_NAME,
+    SET_MINTER_ALLOWANCE_ENTRY_POINT_NAME, SIGNATURE, SNAPSHOT_ENTRY_POINT_NAME, SNAPSHOT_ID_ARG,
+    SPENDER, SYMBOL_ENTRY_POINT_NAME, TIMESTAMP, TOTAL_SUPPLY_AT_ENTRY_POINT_NAME,
+    TOTAL_SUPPLY_ENTRY_POINT_NAME, TRANSFER_AND_CALL_ENTRY_POINT_NAME, TRANSFER_ENTRY_POINT_NAME,
+    TRANSFER_FROM_AND_CALL_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
+    UNFREEZE_ACCOUNT_ENTRY_POINT_NAME, UNPAUSE_ENTRY_POINT_NAME,
 };
+use cep18_types::SecurityBadge;
 
 /// Returns the `name` entry point.
 pub fn name() -> EntryPoint {
@@ -73,13 +92,162 @@ pub fn approve() -> EntryPoint {
         vec![
             Parameter::new(SPENDER, Key::cl_type()),
             Parameter::new(AMOUNT, U256::cl_type()),
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "expires_at" : u64
+            */
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `permit` entry point.
+pub fn permit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PERMIT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER_PUBLIC_KEY, PublicKey::cl_type()),
+            Parameter::new(SPENDER, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(DEADLINE, u64::cl_type()),
+            Parameter::new(NONCE, u64::cl_type()),
+            Parameter::new(SIGNATURE, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `nonce_of` entry point.
+pub fn nonce_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(NONCE_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(OWNER, Key::cl_type())],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `holder_count` entry point.
+pub fn holder_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HOLDER_COUNT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `holder_at` entry point.
+pub fn holder_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HOLDER_AT_ENTRY_POINT_NAME),
+        vec![Parameter::new(INDEX, u64::cl_type())],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `index_accounts` entry point.
+pub fn index_accounts() -> EntryPoint {
+    EntryPoint::new(
+        String::from(INDEX_ACCOUNTS_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNTS, Vec::<Key>::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `snapshot` entry point.
+pub fn snapshot() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SNAPSHOT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `balance_of_at` entry point.
+pub fn balance_of_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BALANCE_OF_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS, Key::cl_type()),
+            Parameter::new(SNAPSHOT_ID_ARG, u64::cl_type()),
         ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `total_supply_at` entry point.
+pub fn total_supply_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOTAL_SUPPLY_AT_ENTRY_POINT_NAME),
+        vec![Parameter::new(SNAPSHOT_ID_ARG, u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `delegate` entry point.
+pub fn delegate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DELEGATE_ENTRY_POINT_NAME),
+        vec![Parameter::new(DELEGATEE, Key::cl_type())],
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Contract,
     )
 }
 
+/// Returns the `delegates` entry point.
+pub fn delegates() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DELEGATES_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        Option::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_votes` entry point.
+pub fn get_votes() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_VOTES_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_past_votes` entry point.
+pub fn get_past_votes() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_PAST_VOTES_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ACCOUNT, Key::cl_type()),
+            Parameter::new(TIMESTAMP, u64::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `increase_allowance` entry point.
 pub fn increase_allowance() -> EntryPoint {
     EntryPoint::new(
@@ -87,6 +255,10 @@ pub fn increase_allowance() -> EntryPoint {
         vec![
             Parameter::new(SPENDER, Key::cl_type()),
             Parameter::new(AMOUNT, U256::cl_type()),
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "expires_at" : u64
+            */
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -122,6 +294,70 @@ pub fn transfer() -> EntryPoint {
     )
 }
 
+/// Returns the `transfer_and_call` entry point.
+pub fn transfer_and_call() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRANSFER_AND_CALL_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(DATA, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `transfer_from_and_call` entry point.
+pub fn transfer_from_and_call() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRANSFER_FROM_AND_CALL_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER, Key::cl_type()),
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(DATA, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `on_cep18_received` entry point contracts expose to receive tokens through
+/// `transfer_and_call` and `transfer_from_and_call`. It returns whether the tokens are accepted.
+///
+/// Not part of the token itself, receiving contracts add it to their own entry points.
+pub fn on_cep18_received() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ON_CEP18_RECEIVED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OPERATOR, Key::cl_type()),
+            Parameter::new(FROM, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(DATA, Bytes::cl_type()),
+        ],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `batch_transfer` entry point.
+pub fn batch_transfer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BATCH_TRANSFER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENTS, Vec::<Key>::cl_type()),
+            Parameter::new(AMOUNTS, Vec::<U256>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `balance_of` entry point.
 pub fn balance_of() -> EntryPoint {
     EntryPoint::new(
@@ -144,6 +380,17 @@ pub fn total_supply() -> EntryPoint {
     )
 }
 
+/// Returns the `max_supply` entry point.
+pub fn max_supply() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MAX_SUPPLY_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `decimals` entry point.
 pub fn decimals() -> EntryPoint {
     EntryPoint::new(
@@ -155,7 +402,7 @@ pub fn decimals() -> EntryPoint {
     )
 }
 
-/// Returns the `burn` entry point.
+/// Returns the `burn` entry point. Calling it for another owner goes through `burn_from`.
 pub fn burn() -> EntryPoint {
     EntryPoint::new(
         String::from(BURN_ENTRY_POINT_NAME),
@@ -169,6 +416,20 @@ pub fn burn() -> EntryPoint {
     )
 }
 
+/// Returns the `burn_from` entry point.
+pub fn burn_from() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BURN_FROM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `mint` entry point.
 pub fn mint() -> EntryPoint {
     EntryPoint::new(
@@ -183,6 +444,20 @@ pub fn mint() -> EntryPoint {
     )
 }
 
+/// Returns the `batch_mint` entry point.
+pub fn batch_mint() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BATCH_MINT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENTS, Vec::<Key>::cl_type()),
+            Parameter::new(AMOUNTS, Vec::<U256>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `change_security` entry point.
 pub fn change_security() -> EntryPoint {
     EntryPoint::new(
@@ -190,10 +465,10 @@ pub fn change_security() -> EntryPoint {
         vec![
             // Optional Arguments (can be added or omitted when calling):
             /*
-            - "admin_list" : Vec<Key>
             - "mint_and_burn_list" : Vec<Key>
             - "minter_list" : Vec<Key>
             - "burner_list" : Vec<Key>
+            - "kyc_admin_list" : Vec<Key>
             - "none_list" : Vec<Key>
             */
         ],
@@ -214,6 +489,286 @@ pub fn init() -> EntryPoint {
     )
 }
 
+/// Returns the `migrate` entry point.
+pub fn migrate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MIGRATE_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `propose_admin` entry point.
+pub fn propose_admin() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PROPOSE_ADMIN_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `accept_admin` entry point.
+pub fn accept_admin() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ACCEPT_ADMIN_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_badge` entry point.
+pub fn get_badge() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_BADGE_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        Option::<SecurityBadge>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `is_minter` entry point.
+pub fn is_minter() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_MINTER_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_minter_allowance` entry point.
+pub fn set_minter_allowance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_MINTER_ALLOWANCE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ACCOUNT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `minter_allowance` entry point.
+pub fn minter_allowance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINTER_ALLOWANCE_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `is_admin` entry point.
+pub fn is_admin() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_ADMIN_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACCOUNT, Key::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `role_member_count` entry point.
+pub fn role_member_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ROLE_MEMBER_COUNT_ENTRY_POINT_NAME),
+        vec![Parameter::new(BADGE, SecurityBadge::cl_type())],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `role_member_at` entry point.
+pub fn role_member_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ROLE_MEMBER_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(BADGE, SecurityBadge::cl_type()),
+            Parameter::new(INDEX, u64::cl_type()),
+        ],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `propose` entry point.
+pub fn propose() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PROPOSE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ACTION, u8::cl_type()),
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "owner" : Key
+            - "amount" : U256
+            - "account" : Key
+            - "threshold" : u64
+            - "max_supply" : U256
+            - "operation_id" : u64
+            - "mint_and_burn_list" : Vec<Key>
+            - "minter_list" : Vec<Key>
+            - "burner_list" : Vec<Key>
+            - "kyc_admin_list" : Vec<Key>
+            - "none_list" : Vec<Key>
+            */
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `approve_proposal` entry point.
+pub fn approve_proposal() -> EntryPoint {
+    EntryPoint::new(
+        String::from(APPROVE_PROPOSAL_ENTRY_POINT_NAME),
+        vec![Parameter::new(PROPOSAL_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `cancel_proposal` entry point.
+pub fn cancel_proposal() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_PROPOSAL_ENTRY_POINT_NAME),
+        vec![Parameter::new(PROPOSAL_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `schedule` entry point.
+pub fn schedule() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SCHEDULE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ACTION, u8::cl_type()),
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "owner" : Key
+            - "amount" : U256
+            - "account" : Key
+            - "threshold" : u64
+            - "max_supply" : U256
+            - "operation_id" : u64
+            - "mint_and_burn_list" : Vec<Key>
+            - "minter_list" : Vec<Key>
+            - "burner_list" : Vec<Key>
+            - "kyc_admin_list" : Vec<Key>
+            - "none_list" : Vec<Key>
+            */
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `execute` entry point.
+pub fn execute() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EXECUTE_ENTRY_POINT_NAME),
+        vec![Parameter::new(OPERATION_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `cancel` entry point.
+pub fn cancel() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_ENTRY_POINT_NAME),
+        vec![Parameter::new(OPERATION_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `pause` entry point.
+pub fn pause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PAUSE_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `unpause` entry point.
+pub fn unpause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNPAUSE_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `freeze_account` entry point.
+pub fn freeze_account() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FREEZE_ACCOUNT_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `unfreeze_account` entry point.
+pub fn unfreeze_account() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNFREEZE_ACCOUNT_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `add_to_whitelist` entry point.
+pub fn add_to_whitelist() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ADD_TO_WHITELIST_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `remove_from_whitelist` entry point.
+pub fn remove_from_whitelist() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REMOVE_FROM_WHITELIST_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the default set of CEP-18 token entry points.
 pub fn generate_entry_points() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
@@ -222,15 +777,55 @@ pub fn generate_entry_points() -> EntryPoints {
     entry_points.add_entry_point(symbol());
     entry_points.add_entry_point(decimals());
     entry_points.add_entry_point(total_supply());
+    entry_points.add_entry_point(max_supply());
     entry_points.add_entry_point(balance_of());
     entry_points.add_entry_point(transfer());
     entry_points.add_entry_point(approve());
     entry_points.add_entry_point(allowance());
     entry_points.add_entry_point(decrease_allowance());
     entry_points.add_entry_point(increase_allowance());
+    entry_points.add_entry_point(permit());
+    entry_points.add_entry_point(nonce_of());
+    entry_points.add_entry_point(holder_count());
+    entry_points.add_entry_point(holder_at());
+    entry_points.add_entry_point(index_accounts());
+    entry_points.add_entry_point(snapshot());
+    entry_points.add_entry_point(balance_of_at());
+    entry_points.add_entry_point(total_supply_at());
+    entry_points.add_entry_point(delegate());
+    entry_points.add_entry_point(delegates());
+    entry_points.add_entry_point(get_votes());
+    entry_points.add_entry_point(get_past_votes());
     entry_points.add_entry_point(transfer_from());
+    entry_points.add_entry_point(batch_transfer());
+    entry_points.add_entry_point(transfer_and_call());
+    entry_points.add_entry_point(transfer_from_and_call());
     entry_points.add_entry_point(change_security());
+    entry_points.add_entry_point(propose_admin());
+    entry_points.add_entry_point(accept_admin());
+    entry_points.add_entry_point(get_badge());
+    entry_points.add_entry_point(is_minter());
+    entry_points.add_entry_point(is_admin());
+    entry_points.add_entry_point(set_minter_allowance());
+    entry_points.add_entry_point(minter_allowance());
+    entry_points.add_entry_point(role_member_count());
+    entry_points.add_entry_point(role_member_at());
+    entry_points.add_entry_point(propose());
+    entry_points.add_entry_point(approve_proposal());
+    entry_points.add_entry_point(cancel_proposal());
+    entry_points.add_entry_point(schedule());
+    entry_points.add_entry_point(execute());
+    entry_points.add_entry_point(cancel());
     entry_points.add_entry_point(burn());
+    entry_points.add_entry_point(burn_from());
     entry_points.add_entry_point(mint());
+    entry_points.add_entry_point(batch_mint());
+    entry_points.add_entry_point(migrate());
+    entry_points.add_entry_point(pause());
+    entry_points.add_entry_point(unpause());
+    entry_points.add_entry_point(freeze_account());
+    entry_points.add_entry_point(unfreeze_account());
+    entry_points.add_entry_point(add_to_whitelist());
+    entry_points.add_entry_point(remove_from_whitelist());
     entry_points
 }
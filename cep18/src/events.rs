@@ -1,16 +1,22 @@
 use core::convert::TryFrom;
 
-use alloc::collections::BTreeMap;
-use casper_contract::unwrap_or_revert::UnwrapOrRevert;
-use casper_types::{Key, U256};
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
 
 use crate::{
     constants::EVENTS_MODE,
     modalities::EventsMode,
-    utils::{read_from, SecurityBadge},
+    utils::{get_uref, read_from},
 };
 
-use casper_event_standard::{emit, Event, Schemas};
+use casper_event_standard::{emit, EVENTS_SCHEMA};
+use cep18_types::events::schemas;
+pub(crate) use cep18_types::events::{
+    AcceptAdmin, AddToWhitelist, ApproveProposal, Burn, BurnFrom, CancelOperation, CancelProposal,
+    Cep18Event as Event, ChangeSecurity, CreateProposal, DecreaseAllowance, DelegateChanged,
+    DelegateVotesChanged, ExecuteOperation, ExecuteProposal, FreezeAccount, IncreaseAllowance,
+    Mint, Paused, ProposeAdmin, RemoveFromWhitelist, ScheduleOperation, SetAllowance,
+    SetMinterAllowance, Snapshot, Transfer, TransferFrom, UnfreezeAccount, Unpaused,
+};
 
 pub fn record_event_dictionary(event: Event) {
     let events_mode: EventsMode =
@@ -22,83 +28,36 @@ pub fn record_event_dictionary(event: Event) {
     }
 }
 
-pub enum Event {
-    Mint(Mint),
-    Burn(Burn),
-    SetAllowance(SetAllowance),
-    IncreaseAllowance(IncreaseAllowance),
-    DecreaseAllowance(DecreaseAllowance),
-    Transfer(Transfer),
-    TransferFrom(TransferFrom),
-    ChangeSecurity(ChangeSecurity),
-}
-
-#[derive(Event, Debug, PartialEq, Eq)]
-pub struct Mint {
-    pub recipient: Key,
-    pub amount: U256,
-}
-
-#[derive(Event, Debug, PartialEq, Eq)]
-pub struct Burn {
-    pub owner: Key,
-    pub amount: U256,
-}
-
-#[derive(Event, Debug, PartialEq, Eq)]
-pub struct SetAllowance {
-    pub owner: Key,
-    pub spender: Key,
-    pub allowance: U256,
-}
-
-#[derive(Event, Debug, PartialEq, Eq)]
-pub struct IncreaseAllowance {
-    pub owner: Key,
-    pub spender: Key,
-    pub allowance: U256,
-    pub inc_by: U256,
-}
-
-#[derive(Event, Debug, PartialEq, Eq)]
-pub struct DecreaseAllowance {
-    pub owner: Key,
-    pub spender: Key,
-    pub allowance: U256,
-    pub decr_by: U256,
-}
-
-#[derive(Event, Debug, PartialEq, Eq)]
-pub struct Transfer {
-    pub sender: Key,
-    pub recipient: Key,
-    pub amount: U256,
-}
-
-#[derive(Event, Debug, PartialEq, Eq)]
-pub struct TransferFrom {
-    pub spender: Key,
-    pub owner: Key,
-    pub recipient: Key,
-    pub amount: U256,
-}
-
-#[derive(Event, Debug, PartialEq, Eq)]
-pub struct ChangeSecurity {
-    pub admin: Key,
-    pub sec_change_map: BTreeMap<Key, SecurityBadge>,
-}
-
 fn ces(event: Event) {
     match event {
         Event::Mint(ev) => emit(ev),
         Event::Burn(ev) => emit(ev),
+        Event::BurnFrom(ev) => emit(ev),
         Event::SetAllowance(ev) => emit(ev),
         Event::IncreaseAllowance(ev) => emit(ev),
         Event::DecreaseAllowance(ev) => emit(ev),
         Event::Transfer(ev) => emit(ev),
         Event::TransferFrom(ev) => emit(ev),
         Event::ChangeSecurity(ev) => emit(ev),
+        Event::ProposeAdmin(ev) => emit(ev),
+        Event::AcceptAdmin(ev) => emit(ev),
+        Event::CreateProposal(ev) => emit(ev),
+        Event::ApproveProposal(ev) => emit(ev),
+        Event::ExecuteProposal(ev) => emit(ev),
+        Event::CancelProposal(ev) => emit(ev),
+        Event::ScheduleOperation(ev) => emit(ev),
+        Event::ExecuteOperation(ev) => emit(ev),
+        Event::CancelOperation(ev) => emit(ev),
+        Event::SetMinterAllowance(ev) => emit(ev),
+        Event::Paused(ev) => emit(ev),
+        Event::Unpaused(ev) => emit(ev),
+        Event::Snapshot(ev) => emit(ev),
+        Event::DelegateChanged(ev) => emit(ev),
+        Event::DelegateVotesChanged(ev) => emit(ev),
+        Event::FreezeAccount(ev) => emit(ev),
+        Event::UnfreezeAccount(ev) => emit(ev),
+        Event::AddToWhitelist(ev) => emit(ev),
+        Event::RemoveFromWhitelist(ev) => emit(ev),
     }
 }
 
@@ -107,15 +66,17 @@ pub fn init_events() {
         EventsMode::try_from(read_from::<u8>(EVENTS_MODE)).unwrap_or_revert();
 
     if events_mode == EventsMode::CES {
-        let schemas = Schemas::new()
-            .with::<Mint>()
-            .with::<Burn>()
-            .with::<SetAllowance>()
-            .with::<IncreaseAllowance>()
-            .with::<DecreaseAllowance>()
-            .with::<Transfer>()
-            .with::<TransferFrom>()
-            .with::<ChangeSecurity>();
-        casper_event_standard::init(schemas);
+        casper_event_standard::init(schemas());
+    }
+}
+
+/// Overwrites the stored CES schemas of an upgraded contract so events introduced by the new
+/// version can be decoded.
+pub fn upgrade_events() {
+    let events_mode: EventsMode =
+        EventsMode::try_from(read_from::<u8>(EVENTS_MODE)).unwrap_or_revert();
+
+    if events_mode == EventsMode::CES {
+        storage::write(get_uref(EVENTS_SCHEMA), schemas());
     }
 }
@@ -0,0 +1,777 @@
+//! Bodies of the standard entry points, reading their arguments from the runtime. A token binary
+//! exports each of them as a `#[no_mangle] extern "C"` function of the same name.
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+
+use casper_contract::{
+    contract_api::{
+        runtime::{self, get_caller, get_key, get_named_arg, put_key, revert},
+        storage,
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::Bytes, CLValue, Key, PublicKey, U256};
+
+use crate::{
+    allowances::{
+        get_allowance_expirations_uref, get_allowances_uref, get_optional_expires_at_arg,
+        read_allowance_from, write_allowance_expiration_to, write_allowance_to,
+    },
+    balances::{
+        batch_transfer_balance, get_balances_uref, mint_balances, read_balance_from,
+        write_balance_to,
+    },
+    constants::{
+        ACCOUNT, ACCOUNTS, ADDRESS, ALLOWANCES, ALLOWANCE_EXPIRATIONS, AMOUNT, AMOUNTS, BADGE,
+        BALANCES, BALANCE_SNAPSHOTS, BALANCE_SNAPSHOT_COUNTS, DATA, DEADLINE, DECIMALS, DELEGATEE,
+        DELEGATES, ENABLE_MINT_BURN, FROZEN_ACCOUNTS, HOLDERS, HOLDER_COUNT, HOLDER_INDICES, INDEX,
+        MAX_SUPPLY, MIGRATED_CONTRACT, MINTER_ALLOWANCES, NAME, NONCE, NONCES, OPERATIONS,
+        OPERATION_COUNT, OPERATION_ID, OWNER, OWNER_PUBLIC_KEY, PACKAGE_HASH, PAUSED,
+        PENDING_ADMIN, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_ID, PROPOSAL_THRESHOLD, RECIPIENT,
+        RECIPIENTS, ROLE_MEMBERS, ROLE_MEMBER_COUNTS, ROLE_MEMBER_INDICES, SECURITY_BADGES,
+        SIGNATURE, SNAPSHOT_ID, SNAPSHOT_ID_ARG, SPENDER, SYMBOL, TIMELOCK_DELAY, TIMESTAMP,
+        TOTAL_SUPPLY, TOTAL_SUPPLY_SNAPSHOTS, TOTAL_SUPPLY_SNAPSHOT_COUNT, TRANSFER_FILTER_MODE,
+        UPGRADE_READY, VOTE_CHECKPOINTS, VOTE_CHECKPOINT_COUNTS, WHITELIST,
+    },
+    events::{
+        self, init_events, AcceptAdmin, AddToWhitelist, Event, FreezeAccount, Mint, Paused,
+        RemoveFromWhitelist, SetAllowance, SetMinterAllowance, Snapshot, Transfer, UnfreezeAccount,
+        Unpaused,
+    },
+    frozen_accounts::{ensure_not_frozen, get_frozen_accounts_uref, write_frozen_to},
+    holders,
+    minter_allowances::{
+        get_minter_allowances_uref, raises_minter_allowance, read_minter_allowance_from,
+        spend_minter_allowance, write_minter_allowance_to,
+    },
+    modalities::TransferFilterMode,
+    permit::{self, get_nonces_uref, read_nonce_from, write_nonce_to},
+    proposals, roles, snapshots, timelock,
+    utils::{self, get_immediate_caller_address, read_from, sec_check},
+    votes,
+    whitelist::{get_whitelist_uref, write_whitelisted_to},
+    Cep18Error, SecurityBadge,
+};
+
+pub fn name() {
+    runtime::ret(CLValue::from_t(utils::read_from::<String>(NAME)).unwrap_or_revert());
+}
+
+pub fn symbol() {
+    runtime::ret(CLValue::from_t(utils::read_from::<String>(SYMBOL)).unwrap_or_revert());
+}
+
+pub fn decimals() {
+    runtime::ret(CLValue::from_t(utils::read_from::<u8>(DECIMALS)).unwrap_or_revert());
+}
+
+pub fn total_supply() {
+    runtime::ret(CLValue::from_t(utils::read_from::<U256>(TOTAL_SUPPLY)).unwrap_or_revert());
+}
+
+pub fn max_supply() {
+    runtime::ret(CLValue::from_t(utils::read_from::<U256>(MAX_SUPPLY)).unwrap_or_revert());
+}
+
+pub fn balance_of() {
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    let balances_uref = get_balances_uref();
+    let balance = read_balance_from(balances_uref, address);
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+pub fn holder_count() {
+    let holder_count = holders::read_holder_count();
+    runtime::ret(CLValue::from_t(holder_count).unwrap_or_revert());
+}
+
+pub fn holder_at() {
+    let index: u64 = runtime::get_named_arg(INDEX);
+    let holder = holders::read_holder_at(index).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(holder).unwrap_or_revert());
+}
+
+/// Admin EntryPoint to add `accounts` holding tokens to the holder index, and accounts holding a
+/// security badge to its members, for accounts funded or given a badge before a contract version
+/// that kept these indexes. Accounts already indexed, without tokens or without a badge are left
+/// as is.
+pub fn index_accounts() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let accounts: Vec<Key> = runtime::get_named_arg(ACCOUNTS);
+    let balances_uref = get_balances_uref();
+    for account in accounts {
+        holders::update_holder_index(account, read_balance_from(balances_uref, account));
+        if let Some(badge) = utils::read_sec_badge(account) {
+            roles::update_role_membership(account, badge);
+        }
+    }
+}
+
+pub fn allowance() {
+    let spender: Key = runtime::get_named_arg(SPENDER);
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let allowances_uref = get_allowances_uref();
+    let val: U256 = read_allowance_from(allowances_uref, owner, spender);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+pub fn approve() {
+    let owner = utils::get_immediate_caller_address().unwrap_or_revert();
+    let spender: Key = runtime::get_named_arg(SPENDER);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let expires_at = get_optional_expires_at_arg();
+    crate::approve(owner, spender, amount, expires_at);
+}
+
+pub fn decrease_allowance() {
+    let owner = utils::get_immediate_caller_address().unwrap_or_revert();
+    let spender: Key = runtime::get_named_arg(SPENDER);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    crate::decrease_allowance(owner, spender, amount);
+}
+
+pub fn increase_allowance() {
+    let owner = utils::get_immediate_caller_address().unwrap_or_revert();
+    let spender: Key = runtime::get_named_arg(SPENDER);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let expires_at = get_optional_expires_at_arg();
+    crate::increase_allowance(owner, spender, amount, expires_at);
+}
+
+/// Sets the allowance of `spender` over the tokens of the owner of `owner_public_key` using a
+/// signature made off-chain by that owner, so the owner does not have to pay for the deploy.
+///
+/// The signed message is described in `permit::make_permit_message`. `nonce` must match the
+/// current nonce of the owner, see `nonce_of`, and the permit cannot be used after `deadline`
+/// (block time in milliseconds).
+pub fn permit() {
+    utils::ensure_not_paused();
+    let owner_public_key: PublicKey = runtime::get_named_arg(OWNER_PUBLIC_KEY);
+    let owner = Key::Account(owner_public_key.to_account_hash());
+    let spender: Key = runtime::get_named_arg(SPENDER);
+    if spender == owner {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    ensure_not_frozen(&[owner, spender]);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let deadline: u64 = runtime::get_named_arg(DEADLINE);
+    let nonce: u64 = runtime::get_named_arg(NONCE);
+    let signature: Bytes = runtime::get_named_arg(SIGNATURE);
+
+    if u64::from(runtime::get_blocktime()) > deadline {
+        revert(Cep18Error::PermitExpired);
+    }
+    let nonces_uref = get_nonces_uref();
+    if nonce != read_nonce_from(nonces_uref, owner) {
+        revert(Cep18Error::InvalidNonce);
+    }
+    let message = permit::make_permit_message(owner, spender, amount, deadline, nonce);
+    permit::verify_signature(&message, &signature, &owner_public_key).unwrap_or_revert();
+
+    write_nonce_to(nonces_uref, owner, nonce + 1);
+    write_allowance_to(get_allowances_uref(), owner, spender, amount);
+    write_allowance_expiration_to(get_allowance_expirations_uref(), owner, spender, None);
+    events::record_event_dictionary(Event::SetAllowance(SetAllowance {
+        owner,
+        spender,
+        allowance: amount,
+    }))
+}
+
+pub fn nonce_of() {
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let nonce = read_nonce_from(get_nonces_uref(), owner);
+    runtime::ret(CLValue::from_t(nonce).unwrap_or_revert());
+}
+
+pub fn transfer() {
+    let sender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    crate::transfer(sender, recipient, amount);
+}
+
+/// Transfers tokens from the caller to several recipients at once. `recipients` and `amounts` are
+/// matched by position.
+pub fn batch_transfer() {
+    utils::ensure_not_paused();
+    let sender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipients: Vec<Key> = runtime::get_named_arg(RECIPIENTS);
+    let amounts: Vec<U256> = runtime::get_named_arg(AMOUNTS);
+    if recipients.len() != amounts.len() {
+        revert(Cep18Error::BatchLengthMismatch);
+    }
+    if recipients.contains(&sender) {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    ensure_not_frozen(&[sender]);
+    ensure_not_frozen(&recipients);
+
+    let transfers: Vec<(Key, U256)> = recipients.into_iter().zip(amounts).collect();
+    batch_transfer_balance(sender, &transfers).unwrap_or_revert();
+    // Legs of a zero amount move no tokens, so they are not recorded either.
+    for (recipient, amount) in transfers
+        .into_iter()
+        .filter(|(_, amount)| !amount.is_zero())
+    {
+        events::record_event_dictionary(Event::Transfer(Transfer {
+            sender,
+            recipient,
+            amount,
+        }))
+    }
+}
+
+pub fn transfer_from() {
+    let spender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    crate::transfer_from(spender, owner, recipient, amount);
+}
+
+/// Transfers tokens from the caller to a contract package, then calls its `on_cep18_received`
+/// entry point with `data`. The transfer is reverted if the recipient rejects the tokens.
+pub fn transfer_and_call() {
+    let sender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let data: Bytes = runtime::get_named_arg(DATA);
+    crate::transfer_and_call(sender, recipient, amount, data);
+}
+
+/// Same as `transfer_and_call` for tokens of `owner` spent through the allowance of the caller.
+pub fn transfer_from_and_call() {
+    let spender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let data: Bytes = runtime::get_named_arg(DATA);
+    crate::transfer_from_and_call(spender, owner, recipient, amount, data);
+}
+
+pub fn mint() {
+    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
+        revert(Cep18Error::MintBurnDisabled);
+    }
+
+    sec_check(minter_badges());
+
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+
+    spend_caller_minter_allowance(amount);
+    crate::mint(owner, amount);
+}
+
+/// Mints tokens to several recipients at once. `recipients` and `amounts` are matched by position.
+pub fn batch_mint() {
+    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
+        revert(Cep18Error::MintBurnDisabled);
+    }
+    utils::ensure_not_paused();
+
+    sec_check(minter_badges());
+
+    let recipients: Vec<Key> = runtime::get_named_arg(RECIPIENTS);
+    let amounts: Vec<U256> = runtime::get_named_arg(AMOUNTS);
+    if recipients.len() != amounts.len() {
+        revert(Cep18Error::BatchLengthMismatch);
+    }
+    let total_amount = amounts
+        .iter()
+        .try_fold(U256::zero(), |total, amount| total.checked_add(*amount))
+        .unwrap_or_revert_with(Cep18Error::Overflow);
+
+    spend_caller_minter_allowance(total_amount);
+    let mints: Vec<(Key, U256)> = recipients.into_iter().zip(amounts).collect();
+    mint_balances(&mints).unwrap_or_revert();
+    for (recipient, amount) in mints {
+        events::record_event_dictionary(Event::Mint(Mint { recipient, amount }))
+    }
+}
+
+/// Badges allowed to mint. Admins can only mint on their own while a single approval is enough to
+/// carry out a proposal, otherwise minting as an Admin goes through `propose`.
+fn minter_badges() -> Vec<SecurityBadge> {
+    let mut badges = vec![SecurityBadge::Minter, SecurityBadge::MintAndBurn];
+    if proposals::read_threshold() <= 1 {
+        badges.push(SecurityBadge::Admin);
+    }
+    badges
+}
+
+/// Consumes `amount` from the minter allowance of the caller. Admins do not have one.
+fn spend_caller_minter_allowance(amount: U256) {
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    if utils::read_sec_badge(caller) != Some(SecurityBadge::Admin) {
+        spend_minter_allowance(caller, amount).unwrap_or_revert();
+    }
+}
+
+/// Admin EntryPoint to set how many tokens `account` can still mint. Lowering an allowance takes
+/// effect right away, while raising it goes through `propose` or `schedule` once the proposal
+/// threshold is above 1 or the timelock is on.
+pub fn set_minter_allowance() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let minter: Key = runtime::get_named_arg(ACCOUNT);
+    let allowance: U256 = runtime::get_named_arg(AMOUNT);
+    if raises_minter_allowance(minter, allowance) {
+        proposals::ensure_no_proposal_required();
+        timelock::ensure_no_timelock_required();
+    }
+    write_minter_allowance_to(get_minter_allowances_uref(), minter, allowance);
+    events::record_event_dictionary(Event::SetMinterAllowance(SetMinterAllowance {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        minter,
+        allowance,
+    }));
+}
+
+pub fn minter_allowance() {
+    let minter: Key = runtime::get_named_arg(ACCOUNT);
+    let allowance = read_minter_allowance_from(get_minter_allowances_uref(), minter);
+    runtime::ret(CLValue::from_t(allowance).unwrap_or_revert());
+}
+
+/// Burns tokens of the caller. Burning the tokens of another `owner` is the same operation as
+/// `burn_from`: the allowance `owner` granted the caller is what authorizes it, whatever badge the
+/// caller holds.
+pub fn burn() {
+    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
+        revert(Cep18Error::MintBurnDisabled);
+    }
+
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    if owner == caller {
+        crate::burn(owner, amount);
+    } else {
+        crate::burn_from(caller, owner, amount);
+    }
+}
+
+/// Burns tokens of `owner` on behalf of the caller, consuming the allowance `owner` granted them.
+pub fn burn_from() {
+    let spender = get_immediate_caller_address().unwrap_or_revert();
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    crate::burn_from(spender, owner, amount);
+}
+
+/// Initiates the contracts states. Only used by the installer call,
+/// later calls will cause it to revert.
+pub fn init() {
+    if get_key(ALLOWANCES).is_some() {
+        revert(Cep18Error::AlreadyInitialized);
+    }
+    let package_hash = get_named_arg::<Key>(PACKAGE_HASH);
+    put_key(PACKAGE_HASH, package_hash);
+    storage::new_dictionary(ALLOWANCES).unwrap_or_revert();
+    let balances_uref = storage::new_dictionary(BALANCES).unwrap_or_revert();
+    storage::new_dictionary(FROZEN_ACCOUNTS).unwrap_or_revert();
+    let whitelist_uref = storage::new_dictionary(WHITELIST).unwrap_or_revert();
+    storage::new_dictionary(NONCES).unwrap_or_revert();
+    storage::new_dictionary(ALLOWANCE_EXPIRATIONS).unwrap_or_revert();
+    put_key(HOLDER_COUNT, storage::new_uref(0u64).into());
+    storage::new_dictionary(HOLDERS).unwrap_or_revert();
+    storage::new_dictionary(HOLDER_INDICES).unwrap_or_revert();
+    put_key(SNAPSHOT_ID, storage::new_uref(0u64).into());
+    storage::new_dictionary(BALANCE_SNAPSHOTS).unwrap_or_revert();
+    storage::new_dictionary(BALANCE_SNAPSHOT_COUNTS).unwrap_or_revert();
+    storage::new_dictionary(TOTAL_SUPPLY_SNAPSHOTS).unwrap_or_revert();
+    put_key(TOTAL_SUPPLY_SNAPSHOT_COUNT, storage::new_uref(0u64).into());
+    storage::new_dictionary(DELEGATES).unwrap_or_revert();
+    storage::new_dictionary(VOTE_CHECKPOINTS).unwrap_or_revert();
+    storage::new_dictionary(VOTE_CHECKPOINT_COUNTS).unwrap_or_revert();
+    let initial_supply = runtime::get_named_arg(TOTAL_SUPPLY);
+    let caller = get_caller();
+    write_balance_to(balances_uref, caller.into(), initial_supply);
+    // The installer holds the initial supply, so it has to be able to receive tokens back.
+    write_whitelisted_to(whitelist_uref, caller.into(), true);
+
+    put_key(
+        PENDING_ADMIN,
+        storage::new_uref(Option::<(Key, Key)>::None).into(),
+    );
+    storage::new_dictionary(SECURITY_BADGES).unwrap_or_revert();
+    storage::new_dictionary(ROLE_MEMBERS).unwrap_or_revert();
+    storage::new_dictionary(ROLE_MEMBER_COUNTS).unwrap_or_revert();
+    storage::new_dictionary(ROLE_MEMBER_INDICES).unwrap_or_revert();
+    put_key(PROPOSAL_COUNT, storage::new_uref(0u64).into());
+    storage::new_dictionary(PROPOSALS).unwrap_or_revert();
+    put_key(OPERATION_COUNT, storage::new_uref(0u64).into());
+    storage::new_dictionary(OPERATIONS).unwrap_or_revert();
+    put_key(
+        MIGRATED_CONTRACT,
+        storage::new_uref(utils::get_current_contract_hash()).into(),
+    );
+    let minter_allowances_uref = storage::new_dictionary(MINTER_ALLOWANCES).unwrap_or_revert();
+
+    init_events();
+
+    // The installer is an Admin unless the badge lists say otherwise.
+    let mut badge_map = BTreeMap::new();
+    badge_map.insert(Key::from(caller), SecurityBadge::Admin);
+    badge_map.extend(utils::read_sec_badge_lists());
+    utils::change_sec_badge(&badge_map);
+
+    // Minters listed at install are not limited until an Admin sets their allowance, minters
+    // granted a badge afterwards cannot mint until then.
+    for (account, badge) in badge_map {
+        if matches!(badge, SecurityBadge::Minter | SecurityBadge::MintAndBurn) {
+            write_minter_allowance_to(minter_allowances_uref, account, U256::MAX);
+        }
+    }
+}
+
+/// Admin EntryPoint to manipulate the security access granted to users.
+/// One user can only possess one access group badge.
+/// Change strength: None > Admin > KycAdmin > MintAndBurn > Minter > Burner
+/// Change strength meaning by example: If user is added to both Minter and Admin they will be an
+/// Admin, also if a user is added to Admin and None then they will be removed from having rights.
+/// Reverts if the change would leave the contract without any Admin, if it has to go through
+/// `propose` because the proposal threshold is above 1, or if it has to be scheduled because the
+/// timelock is on.
+pub fn change_security() {
+    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
+        revert(Cep18Error::MintBurnDisabled);
+    }
+    sec_check(vec![SecurityBadge::Admin]);
+    proposals::ensure_no_proposal_required();
+    timelock::ensure_no_timelock_required();
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    crate::change_security(caller, utils::read_sec_badge_lists());
+}
+
+/// Returns the badge of `account`, or `None` if it was never given one. Accounts whose badge was
+/// taken away hold `SecurityBadge::None` instead.
+pub fn get_badge() {
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    let badge = utils::read_sec_badge(account);
+    runtime::ret(CLValue::from_t(badge).unwrap_or_revert());
+}
+
+/// Returns whether `account` holds a badge allowing it to mint. The minter allowance is not looked
+/// at, so a Minter with no allowance left is still reported. Admins are only reported while the
+/// proposal threshold is 1, like `mint` only lets them mint on their own then.
+pub fn is_minter() {
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    let is_minter =
+        utils::read_sec_badge(account).map_or(false, |badge| minter_badges().contains(&badge));
+    runtime::ret(CLValue::from_t(is_minter).unwrap_or_revert());
+}
+
+/// Returns whether `account` holds the Admin badge, whatever the proposal threshold.
+pub fn is_admin() {
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    let is_admin = utils::read_sec_badge(account) == Some(SecurityBadge::Admin);
+    runtime::ret(CLValue::from_t(is_admin).unwrap_or_revert());
+}
+
+pub fn role_member_count() {
+    let badge: SecurityBadge = runtime::get_named_arg(BADGE);
+    let count = roles::read_role_member_count(badge);
+    runtime::ret(CLValue::from_t(count).unwrap_or_revert());
+}
+
+pub fn role_member_at() {
+    let badge: SecurityBadge = runtime::get_named_arg(BADGE);
+    let index: u64 = runtime::get_named_arg(INDEX);
+    let member = roles::read_role_member_at(badge, index).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(member).unwrap_or_revert());
+}
+
+/// Admin EntryPoint to propose `account` as a new Admin, which it becomes once it calls
+/// `accept_admin`. A new proposal replaces the pending one. Once the proposal threshold is above 1
+/// or the timelock is on, candidates are proposed through a `propose_admin` action instead.
+pub fn propose_admin() {
+    sec_check(vec![SecurityBadge::Admin]);
+    proposals::ensure_no_proposal_required();
+    timelock::ensure_no_timelock_required();
+    let candidate: Key = runtime::get_named_arg(ACCOUNT);
+    let admin = get_immediate_caller_address().unwrap_or_revert();
+    crate::propose_admin(admin, candidate);
+}
+
+/// Makes the caller an Admin if it is the pending candidate proposed through `propose_admin`, which
+/// is dropped once the Admin that proposed it loses its badge.
+pub fn accept_admin() {
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    match utils::read_pending_admin() {
+        Some((candidate, _)) if candidate == caller => {}
+        _ => revert(Cep18Error::NotPendingAdmin),
+    }
+    utils::write_pending_admin(None);
+
+    let mut badge_map = BTreeMap::new();
+    badge_map.insert(caller, SecurityBadge::Admin);
+    utils::change_sec_badge(&badge_map);
+    events::record_event_dictionary(Event::AcceptAdmin(AcceptAdmin { admin: caller }));
+}
+
+/// Admin EntryPoint to halt transfers, allowance changes, mints and burns.
+pub fn pause() {
+    sec_check(vec![SecurityBadge::Admin]);
+    storage::write(utils::get_uref(PAUSED), true);
+    events::record_event_dictionary(Event::Paused(Paused {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+    }));
+}
+
+/// Admin EntryPoint to resume a paused contract. Once the proposal threshold is above 1 or the
+/// timelock is on, the contract is resumed through an `unpause` action instead.
+pub fn unpause() {
+    sec_check(vec![SecurityBadge::Admin]);
+    proposals::ensure_no_proposal_required();
+    timelock::ensure_no_timelock_required();
+    storage::write(utils::get_uref(PAUSED), false);
+    events::record_event_dictionary(Event::Unpaused(Unpaused {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+    }));
+}
+
+/// Admin EntryPoint to propose a `change_security`, `mint`, `pause`, `unpause`, a new proposal
+/// threshold, a new maximum supply, a new minter allowance, an upgrade, a `propose_admin` or a
+/// `cancel`, picked through `action`, and returns the proposal id. The proposal counts as approved
+/// by the caller and is carried out once enough Admins approved it through `approve_proposal`, or
+/// scheduled if the action is under timelock.
+pub fn propose() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let action = proposals::get_action_arg();
+    let proposer = get_immediate_caller_address().unwrap_or_revert();
+    let id = proposals::create_proposal(proposer, action).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(id).unwrap_or_revert());
+}
+
+/// Admin EntryPoint to approve a pending proposal, carrying it out if that approval is enough.
+pub fn approve_proposal() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let id: u64 = runtime::get_named_arg(PROPOSAL_ID);
+    let approver = get_immediate_caller_address().unwrap_or_revert();
+    proposals::approve_proposal(approver, id).unwrap_or_revert();
+}
+
+/// Admin EntryPoint to drop a pending proposal, which only the Admin that created it can do.
+pub fn cancel_proposal() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let id: u64 = runtime::get_named_arg(PROPOSAL_ID);
+    let admin = get_immediate_caller_address().unwrap_or_revert();
+    proposals::cancel_proposal(admin, id).unwrap_or_revert();
+}
+
+/// Admin EntryPoint to schedule an action, picked through `action` as for `propose`, and returns
+/// the operation id. Once the proposal threshold is above 1, actions are scheduled through
+/// `propose` instead.
+pub fn schedule() {
+    sec_check(vec![SecurityBadge::Admin]);
+    proposals::ensure_no_proposal_required();
+    let action = proposals::get_action_arg();
+    let admin = get_immediate_caller_address().unwrap_or_revert();
+    let id = timelock::schedule(admin, action);
+    runtime::ret(CLValue::from_t(id).unwrap_or_revert());
+}
+
+/// Admin EntryPoint to carry out a scheduled operation once the timelock delay has passed.
+pub fn execute() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let id: u64 = runtime::get_named_arg(OPERATION_ID);
+    let admin = get_immediate_caller_address().unwrap_or_revert();
+    timelock::execute(admin, id).unwrap_or_revert();
+}
+
+/// Admin EntryPoint to drop a scheduled operation before it is executed. Once the proposal
+/// threshold is above 1, operations are cancelled through a `cancel` action of `propose` instead.
+pub fn cancel() {
+    sec_check(vec![SecurityBadge::Admin]);
+    proposals::ensure_no_proposal_required();
+    let id: u64 = runtime::get_named_arg(OPERATION_ID);
+    let admin = get_immediate_caller_address().unwrap_or_revert();
+    timelock::cancel(admin, id).unwrap_or_revert();
+}
+
+/// Admin EntryPoint to take a snapshot of balances and total supply, returning its id.
+pub fn snapshot() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let id = snapshots::take_snapshot();
+    events::record_event_dictionary(Event::Snapshot(Snapshot {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        id,
+    }));
+    runtime::ret(CLValue::from_t(id).unwrap_or_revert());
+}
+
+pub fn balance_of_at() {
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_ARG);
+    let balance = snapshots::read_balance_at(address, snapshot_id).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+pub fn total_supply_at() {
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_ARG);
+    let total_supply = snapshots::read_total_supply_at(snapshot_id).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(total_supply).unwrap_or_revert());
+}
+
+/// Delegates the voting power of the caller to `delegatee`, which can be the caller itself.
+pub fn delegate() {
+    utils::ensure_not_paused();
+    let delegator = get_immediate_caller_address().unwrap_or_revert();
+    let delegatee: Key = runtime::get_named_arg(DELEGATEE);
+    votes::delegate(delegator, delegatee);
+}
+
+pub fn delegates() {
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    let delegatee = votes::read_delegate_of(account);
+    runtime::ret(CLValue::from_t(delegatee).unwrap_or_revert());
+}
+
+pub fn get_votes() {
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    let votes = votes::read_votes(account);
+    runtime::ret(CLValue::from_t(votes).unwrap_or_revert());
+}
+
+pub fn get_past_votes() {
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    let timestamp: u64 = runtime::get_named_arg(TIMESTAMP);
+    let votes = votes::read_past_votes(account, timestamp).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(votes).unwrap_or_revert());
+}
+
+/// Admin EntryPoint to freeze an account, preventing it from sending, receiving or approving
+/// tokens.
+pub fn freeze_account() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let account: Key = runtime::get_named_arg(ADDRESS);
+    write_frozen_to(get_frozen_accounts_uref(), account, true);
+    events::record_event_dictionary(Event::FreezeAccount(FreezeAccount {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        account,
+    }));
+}
+
+/// Admin EntryPoint to lift the freeze of an account.
+pub fn unfreeze_account() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let account: Key = runtime::get_named_arg(ADDRESS);
+    write_frozen_to(get_frozen_accounts_uref(), account, false);
+    events::record_event_dictionary(Event::UnfreezeAccount(UnfreezeAccount {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        account,
+    }));
+}
+
+/// EntryPoint for Admins and KycAdmins to allow an account to receive tokens when the allowlist
+/// transfer filter mode is enabled.
+pub fn add_to_whitelist() {
+    sec_check(vec![SecurityBadge::Admin, SecurityBadge::KycAdmin]);
+    let account: Key = runtime::get_named_arg(ADDRESS);
+    write_whitelisted_to(get_whitelist_uref(), account, true);
+    events::record_event_dictionary(Event::AddToWhitelist(AddToWhitelist {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        account,
+    }));
+}
+
+/// EntryPoint for Admins and KycAdmins to stop an account from receiving tokens when the allowlist
+/// transfer filter mode is enabled. Tokens it already holds can still be sent.
+pub fn remove_from_whitelist() {
+    sec_check(vec![SecurityBadge::Admin, SecurityBadge::KycAdmin]);
+    let account: Key = runtime::get_named_arg(ADDRESS);
+    write_whitelisted_to(get_whitelist_uref(), account, false);
+    events::record_event_dictionary(Event::RemoveFromWhitelist(RemoveFromWhitelist {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        account,
+    }));
+}
+
+/// Brings the state of a freshly upgraded contract version up to date. Named keys of the previous
+/// version are carried over by the execution engine, so only what changed between versions has to
+/// be handled here. Must stay idempotent as anyone can call it.
+pub fn migrate() {
+    if get_key(PAUSED).is_none() {
+        put_key(PAUSED, storage::new_uref(false).into());
+    }
+    if get_key(FROZEN_ACCOUNTS).is_none() {
+        storage::new_dictionary(FROZEN_ACCOUNTS).unwrap_or_revert();
+    }
+    if get_key(MAX_SUPPLY).is_none() {
+        put_key(MAX_SUPPLY, storage::new_uref(U256::MAX).into());
+    }
+    if get_key(TRANSFER_FILTER_MODE).is_none() {
+        put_key(
+            TRANSFER_FILTER_MODE,
+            storage::new_uref(TransferFilterMode::None as u8).into(),
+        );
+        storage::new_dictionary(WHITELIST).unwrap_or_revert();
+    }
+    if get_key(NONCES).is_none() {
+        storage::new_dictionary(NONCES).unwrap_or_revert();
+    }
+    if get_key(ALLOWANCE_EXPIRATIONS).is_none() {
+        storage::new_dictionary(ALLOWANCE_EXPIRATIONS).unwrap_or_revert();
+    }
+    if get_key(HOLDER_COUNT).is_none() {
+        // Holders funded before this version are indexed through `index_accounts`, or the next
+        // time their balance changes.
+        put_key(HOLDER_COUNT, storage::new_uref(0u64).into());
+        storage::new_dictionary(HOLDERS).unwrap_or_revert();
+        storage::new_dictionary(HOLDER_INDICES).unwrap_or_revert();
+    }
+    if get_key(SNAPSHOT_ID).is_none() {
+        put_key(SNAPSHOT_ID, storage::new_uref(0u64).into());
+        storage::new_dictionary(BALANCE_SNAPSHOTS).unwrap_or_revert();
+        storage::new_dictionary(BALANCE_SNAPSHOT_COUNTS).unwrap_or_revert();
+        storage::new_dictionary(TOTAL_SUPPLY_SNAPSHOTS).unwrap_or_revert();
+        put_key(TOTAL_SUPPLY_SNAPSHOT_COUNT, storage::new_uref(0u64).into());
+    }
+    if get_key(DELEGATES).is_none() {
+        storage::new_dictionary(DELEGATES).unwrap_or_revert();
+        storage::new_dictionary(VOTE_CHECKPOINTS).unwrap_or_revert();
+        storage::new_dictionary(VOTE_CHECKPOINT_COUNTS).unwrap_or_revert();
+    }
+    if get_key(PENDING_ADMIN).is_none() {
+        put_key(
+            PENDING_ADMIN,
+            storage::new_uref(Option::<(Key, Key)>::None).into(),
+        );
+    }
+    if get_key(ROLE_MEMBERS).is_none() {
+        // Badges given by previous versions are listed through `index_accounts`, or the next time
+        // they change. Until then their Admins do not count towards the proposal threshold, nor
+        // against removing the last Admin.
+        storage::new_dictionary(ROLE_MEMBERS).unwrap_or_revert();
+        storage::new_dictionary(ROLE_MEMBER_COUNTS).unwrap_or_revert();
+        storage::new_dictionary(ROLE_MEMBER_INDICES).unwrap_or_revert();
+    }
+    if get_key(PROPOSAL_THRESHOLD).is_none() {
+        put_key(PROPOSAL_THRESHOLD, storage::new_uref(1u64).into());
+        put_key(PROPOSAL_COUNT, storage::new_uref(0u64).into());
+        storage::new_dictionary(PROPOSALS).unwrap_or_revert();
+    }
+    if get_key(TIMELOCK_DELAY).is_none() {
+        put_key(TIMELOCK_DELAY, storage::new_uref(0u64).into());
+        put_key(OPERATION_COUNT, storage::new_uref(0u64).into());
+        storage::new_dictionary(OPERATIONS).unwrap_or_revert();
+    }
+    if get_key(UPGRADE_READY).is_none() {
+        // Previous versions had no timelock, so their upgrades need no `Upgrade` operation.
+        put_key(UPGRADE_READY, storage::new_uref(true).into());
+        put_key(
+            MIGRATED_CONTRACT,
+            storage::new_uref(utils::get_current_contract_hash()).into(),
+        );
+    } else {
+        timelock::record_migration();
+    }
+    if get_key(MINTER_ALLOWANCES).is_none() {
+        // Breaking change: minters of previous versions cannot mint until an Admin gives them an
+        // allowance, since the accounts holding a badge are only known once indexed.
+        storage::new_dictionary(MINTER_ALLOWANCES).unwrap_or_revert();
+    }
+    events::upgrade_events();
+}
@@ -0,0 +1,41 @@
+//! Implementation of frozen accounts.
+use casper_contract::{
+    contract_api::{runtime::revert, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, URef};
+
+use crate::{balances::make_dictionary_item_key, constants::FROZEN_ACCOUNTS, utils, Cep18Error};
+
+/// Getter for the "frozen_accounts" dictionary URef.
+pub(crate) fn get_frozen_accounts_uref() -> URef {
+    utils::get_uref(FROZEN_ACCOUNTS)
+}
+
+/// Writes the frozen status of a specified account into a dictionary.
+pub(crate) fn write_frozen_to(frozen_accounts_uref: URef, address: Key, frozen: bool) {
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_put(frozen_accounts_uref, &dictionary_item_key, frozen);
+}
+
+/// Reads the frozen status of a specified account.
+///
+/// Accounts that were never frozen are reported as not frozen.
+pub(crate) fn read_frozen_from(frozen_accounts_uref: URef, address: Key) -> bool {
+    let dictionary_item_key = make_dictionary_item_key(address);
+
+    storage::dictionary_get(frozen_accounts_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Reverts if any of the given accounts is frozen.
+pub(crate) fn ensure_not_frozen(addresses: &[Key]) {
+    let frozen_accounts_uref = get_frozen_accounts_uref();
+    if addresses
+        .iter()
+        .any(|address| read_frozen_from(frozen_accounts_uref, *address))
+    {
+        revert(Cep18Error::AccountFrozen);
+    }
+}
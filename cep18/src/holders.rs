@@ -0,0 +1,75 @@
+//! Implementation of the enumerable index of token holders.
+use alloc::string::ToString;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{Key, URef, U256};
+
+use crate::{
+    balances::make_dictionary_item_key,
+    constants::{HOLDERS, HOLDER_COUNT, HOLDER_INDICES},
+    utils, Cep18Error,
+};
+
+/// Reads the number of accounts holding a non-zero balance.
+pub(crate) fn read_holder_count() -> u64 {
+    utils::read_from(HOLDER_COUNT)
+}
+
+/// Reads the holder stored at `index`, in no particular order.
+pub(crate) fn read_holder_at(index: u64) -> Result<Key, Cep18Error> {
+    if index >= read_holder_count() {
+        return Err(Cep18Error::HolderIndexOutOfRange);
+    }
+    storage::dictionary_get(utils::get_uref(HOLDERS), &index.to_string())
+        .unwrap_or_revert()
+        .ok_or(Cep18Error::HolderIndexOutOfRange)
+}
+
+/// Adds `address` to, or removes it from, the holder index according to its new `balance`.
+///
+/// Removal moves the last holder into the freed position so the index stays contiguous. Accounts
+/// funded before the index existed are added the next time their balance is written, or through
+/// the `index_accounts` entry point.
+pub(crate) fn update_holder_index(address: Key, balance: U256) {
+    let holders_uref = utils::get_uref(HOLDERS);
+    let indices_uref = utils::get_uref(HOLDER_INDICES);
+    let holder_count_uref = utils::get_uref(HOLDER_COUNT);
+    let holder_count: u64 = storage::read(holder_count_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    let index = read_holder_index_from(indices_uref, address);
+
+    match index {
+        None if !balance.is_zero() => {
+            storage::dictionary_put(holders_uref, &holder_count.to_string(), address);
+            write_holder_index_to(indices_uref, address, Some(holder_count));
+            storage::write(holder_count_uref, holder_count + 1);
+        }
+        Some(index) if balance.is_zero() => {
+            let last_index = holder_count - 1;
+            if index != last_index {
+                let last_holder: Key =
+                    storage::dictionary_get(holders_uref, &last_index.to_string())
+                        .unwrap_or_revert()
+                        .unwrap_or_revert_with(Cep18Error::HolderIndexOutOfRange);
+                storage::dictionary_put(holders_uref, &index.to_string(), last_holder);
+                write_holder_index_to(indices_uref, last_holder, Some(index));
+            }
+            write_holder_index_to(indices_uref, address, None);
+            storage::write(holder_count_uref, last_index);
+        }
+        _ => {}
+    }
+}
+
+fn write_holder_index_to(indices_uref: URef, address: Key, index: Option<u64>) {
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_put(indices_uref, &dictionary_item_key, index);
+}
+
+fn read_holder_index_from(indices_uref: URef, address: Key) -> Option<u64> {
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_get(indices_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .flatten()
+}
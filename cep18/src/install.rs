@@ -0,0 +1,263 @@
+//! Installation and upgrade of the contract.
+use core::convert::TryFrom;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{
+        runtime::{self, revert},
+        storage,
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::NamedKeys, runtime_args, ContractHash, ContractPackageHash, EntryPoint, EntryPoints,
+    Key, RuntimeArgs, U256,
+};
+
+use crate::{
+    constants::{
+        ACCESS_KEY_NAME_PREFIX, ADMIN_LIST, BURNER_LIST, CONTRACT_NAME_PREFIX,
+        CONTRACT_VERSION_PREFIX, DECIMALS, ENABLE_MINT_BURN, EVENTS_MODE, HASH_KEY_NAME_PREFIX,
+        INIT_ENTRY_POINT_NAME, KYC_ADMIN_LIST, MAX_SUPPLY, MIGRATE_ENTRY_POINT_NAME, MINTER_LIST,
+        MINT_AND_BURN_LIST, NAME, PACKAGE_HASH, PAUSED, PROPOSAL_THRESHOLD, SYMBOL, TIMELOCK_DELAY,
+        TOTAL_SUPPLY, TRANSFER_FILTER_MODE, UPGRADE_READY, UPGRADE_READY_PREFIX,
+    },
+    entry_points::generate_entry_points,
+    modalities::TransferFilterMode,
+    utils, Cep18Error,
+};
+
+/// Named keys and entry points a token adds to the standard ones of the contract.
+#[derive(Default)]
+pub struct ContractHooks {
+    /// Named keys stored under the contract.
+    pub named_keys: NamedKeys,
+    /// Entry points exported by the token binary on top of the standard ones, typically built
+    /// like the ones of [`crate::entry_points`].
+    pub entry_points: Vec<EntryPoint>,
+}
+
+/// Installs the contract from the runtime arguments of the installer session, adding the named
+/// keys and entry points of `hooks` to the standard ones.
+pub fn install_contract(hooks: ContractHooks) {
+    let name: String = runtime::get_named_arg(NAME);
+    let symbol: String = runtime::get_named_arg(SYMBOL);
+    let decimals: u8 = runtime::get_named_arg(DECIMALS);
+    let total_supply: U256 = runtime::get_named_arg(TOTAL_SUPPLY);
+    let max_supply: U256 =
+        utils::get_optional_named_arg_with_user_errors(MAX_SUPPLY, Cep18Error::InvalidMaxSupply)
+            .unwrap_or(U256::MAX);
+    if total_supply > max_supply {
+        revert(Cep18Error::ExceedsMaxSupply);
+    }
+    let events_mode: u8 =
+        utils::get_optional_named_arg_with_user_errors(EVENTS_MODE, Cep18Error::InvalidEventsMode)
+            .unwrap_or(0u8);
+
+    let admin_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep18Error::InvalidAdminList);
+    let minter_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(MINTER_LIST, Cep18Error::InvalidMinterList);
+    let burner_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(BURNER_LIST, Cep18Error::InvalidBurnerList);
+    let mint_and_burn_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        MINT_AND_BURN_LIST,
+        Cep18Error::InvalidMintAndBurnList,
+    );
+    let kyc_admin_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        KYC_ADMIN_LIST,
+        Cep18Error::InvalidKycAdminList,
+    );
+
+    let enable_mint_burn: u8 = utils::get_optional_named_arg_with_user_errors(
+        ENABLE_MINT_BURN,
+        Cep18Error::InvalidEnableMBFlag,
+    )
+    .unwrap_or(0);
+
+    let transfer_filter_mode: u8 = utils::get_optional_named_arg_with_user_errors(
+        TRANSFER_FILTER_MODE,
+        Cep18Error::InvalidTransferFilterMode,
+    )
+    .unwrap_or(0);
+    TransferFilterMode::try_from(transfer_filter_mode).unwrap_or_revert();
+
+    let proposal_threshold: u64 = utils::get_optional_named_arg_with_user_errors(
+        PROPOSAL_THRESHOLD,
+        Cep18Error::InvalidProposalThreshold,
+    )
+    .unwrap_or(1);
+    if proposal_threshold == 0 {
+        revert(Cep18Error::InvalidProposalThreshold);
+    }
+
+    let timelock_delay: u64 = utils::get_optional_named_arg_with_user_errors(
+        TIMELOCK_DELAY,
+        Cep18Error::InvalidTimelockDelay,
+    )
+    .unwrap_or(0);
+
+    // Standard named keys take precedence over the ones of the token.
+    let mut named_keys = hooks.named_keys;
+    named_keys.insert(NAME.to_string(), storage::new_uref(name.clone()).into());
+    named_keys.insert(SYMBOL.to_string(), storage::new_uref(symbol).into());
+    named_keys.insert(DECIMALS.to_string(), storage::new_uref(decimals).into());
+    named_keys.insert(
+        TOTAL_SUPPLY.to_string(),
+        storage::new_uref(total_supply).into(),
+    );
+    named_keys.insert(MAX_SUPPLY.to_string(), storage::new_uref(max_supply).into());
+    named_keys.insert(
+        EVENTS_MODE.to_string(),
+        storage::new_uref(events_mode).into(),
+    );
+    named_keys.insert(
+        ENABLE_MINT_BURN.to_string(),
+        storage::new_uref(enable_mint_burn).into(),
+    );
+    named_keys.insert(PAUSED.to_string(), storage::new_uref(false).into());
+    named_keys.insert(
+        PROPOSAL_THRESHOLD.to_string(),
+        storage::new_uref(proposal_threshold).into(),
+    );
+    named_keys.insert(
+        TIMELOCK_DELAY.to_string(),
+        storage::new_uref(timelock_delay).into(),
+    );
+    named_keys.insert(
+        TRANSFER_FILTER_MODE.to_string(),
+        storage::new_uref(transfer_filter_mode).into(),
+    );
+    // Upgrades wait for an executed `Upgrade` operation when the timelock is on. The installer
+    // keeps read access to the flag so `upgrade_contract` can check it before adding a version.
+    let upgrade_ready_uref = storage::new_uref(timelock_delay == 0);
+    named_keys.insert(UPGRADE_READY.to_string(), upgrade_ready_uref.into());
+    runtime::put_key(
+        &format!("{UPGRADE_READY_PREFIX}{name}"),
+        upgrade_ready_uref.into_read().into(),
+    );
+    let entry_points = generate_entry_points_with(hooks.entry_points);
+
+    let hash_key_name = format!("{HASH_KEY_NAME_PREFIX}{name}");
+
+    let (contract_hash, contract_version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(hash_key_name.clone()),
+        Some(format!("{ACCESS_KEY_NAME_PREFIX}{name}")),
+    );
+    let package_hash = runtime::get_key(&hash_key_name).unwrap_or_revert();
+
+    // Store contract_hash and contract_version under the keys CONTRACT_NAME and CONTRACT_VERSION
+    runtime::put_key(
+        &format!("{CONTRACT_NAME_PREFIX}{name}"),
+        contract_hash.into(),
+    );
+    runtime::put_key(
+        &format!("{CONTRACT_VERSION_PREFIX}{name}"),
+        storage::new_uref(contract_version).into(),
+    );
+    // Call contract to initialize it
+    let mut init_args = runtime_args! {TOTAL_SUPPLY => total_supply, PACKAGE_HASH => package_hash};
+
+    if let Some(admin_list) = admin_list {
+        init_args.insert(ADMIN_LIST, admin_list).unwrap_or_revert();
+    }
+    if let Some(minter_list) = minter_list {
+        init_args
+            .insert(MINTER_LIST, minter_list)
+            .unwrap_or_revert();
+    }
+    if let Some(burner_list) = burner_list {
+        init_args
+            .insert(BURNER_LIST, burner_list)
+            .unwrap_or_revert();
+    }
+    if let Some(mint_and_burn_list) = mint_and_burn_list {
+        init_args
+            .insert(MINT_AND_BURN_LIST, mint_and_burn_list)
+            .unwrap_or_revert();
+    }
+    if let Some(kyc_admin_list) = kyc_admin_list {
+        init_args
+            .insert(KYC_ADMIN_LIST, kyc_admin_list)
+            .unwrap_or_revert();
+    }
+
+    runtime::call_contract::<()>(contract_hash, INIT_ENTRY_POINT_NAME, init_args);
+}
+
+/// Adds a new contract version to the package previously installed under `name` and disables the
+/// current one. Balances, allowances, security badges and total supply live under the named keys
+/// of the contract, which are carried over to the new version.
+///
+/// The entry points of `hooks` are added to the standard ones and its named keys to the ones
+/// carried over.
+///
+/// When the timelock is on, the upgrade reverts unless an `Upgrade` operation was executed since
+/// the previous one. Packages installed by versions without a timelock are upgraded right away.
+/// The check runs in this session, so custom session code holding the package access key can still
+/// add a contract version on its own.
+pub fn upgrade_contract(name: &str, hooks: ContractHooks) {
+    if let Some(upgrade_ready_key) = runtime::get_key(&format!("{UPGRADE_READY_PREFIX}{name}")) {
+        let upgrade_ready_uref = upgrade_ready_key.into_uref().unwrap_or_revert();
+        let upgrade_ready: bool = storage::read(upgrade_ready_uref)
+            .unwrap_or_revert()
+            .unwrap_or_revert();
+        if !upgrade_ready {
+            revert(Cep18Error::UpgradeNotScheduled);
+        }
+    }
+
+    let entry_points = generate_entry_points_with(hooks.entry_points);
+
+    let contract_package_hash = runtime::get_key(&format!("{HASH_KEY_NAME_PREFIX}{name}"))
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .unwrap_or_revert_with(Cep18Error::PackageHashMissing);
+    let previous_contract_hash = runtime::get_key(&format!("{CONTRACT_NAME_PREFIX}{name}"))
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .unwrap_or_revert_with(Cep18Error::MissingContractHash);
+
+    let (contract_hash, contract_version) =
+        storage::add_contract_version(contract_package_hash, entry_points, hooks.named_keys);
+    storage::disable_contract_version(contract_package_hash, previous_contract_hash)
+        .unwrap_or_revert();
+
+    runtime::put_key(
+        &format!("{CONTRACT_NAME_PREFIX}{name}"),
+        contract_hash.into(),
+    );
+    runtime::put_key(
+        &format!("{CONTRACT_VERSION_PREFIX}{name}"),
+        storage::new_uref(contract_version).into(),
+    );
+
+    runtime::call_contract::<()>(contract_hash, MIGRATE_ENTRY_POINT_NAME, RuntimeArgs::new());
+}
+
+/// Upgrades the contract if a package was already installed under the `name` argument, installs
+/// it otherwise. Meant to be called from the `call` function of a token binary.
+pub fn install_or_upgrade(hooks: ContractHooks) {
+    let name: String = runtime::get_named_arg(NAME);
+    if runtime::get_key(&format!("{HASH_KEY_NAME_PREFIX}{name}")).is_some() {
+        upgrade_contract(&name, hooks)
+    } else {
+        install_contract(hooks)
+    }
+}
+
+/// Standard entry points of the contract along with `extra_entry_points`.
+fn generate_entry_points_with(extra_entry_points: Vec<EntryPoint>) -> EntryPoints {
+    let mut entry_points = generate_entry_points();
+    for entry_point in extra_entry_points {
+        entry_points.add_entry_point(entry_point);
+    }
+    entry_points
+}
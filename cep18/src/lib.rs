@@ -0,0 +1,294 @@
+//! Library for developing CEP-18 tokens for the Casper network.
+//!
+//! The functions at the root of the crate carry out the token operations for explicit accounts, so
+//! custom entry points can build on them. [`exports`] holds the bodies of the standard entry
+//! points, which a token binary exports as `#[no_mangle] extern "C"` functions, and [`install`]
+//! installs or upgrades the contract along with the named keys and entry points of the token.
+#![no_std]
+
+extern crate alloc;
+
+mod allowances;
+mod balances;
+pub mod entry_points;
+mod events;
+pub mod exports;
+mod frozen_accounts;
+mod holders;
+pub mod install;
+mod minter_allowances;
+mod modalities;
+mod permit;
+mod proposals;
+mod roles;
+mod snapshots;
+mod timelock;
+pub mod utils;
+mod votes;
+mod whitelist;
+
+use allowances::{
+    get_allowance_expirations_uref, get_allowances_uref, has_expired,
+    read_allowance_expiration_from, read_allowance_from, spend_allowance,
+    write_allowance_expiration_to, write_allowance_to,
+};
+use balances::{burn_balance, mint_balances, transfer_balance};
+use frozen_accounts::ensure_not_frozen;
+
+use alloc::collections::BTreeMap;
+use casper_contract::{
+    contract_api::runtime::{self, revert},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::Bytes, runtime_args, ContractPackageHash, Key, RuntimeArgs, U256};
+
+pub use cep18_types::{constants, Cep18Error, SecurityBadge};
+pub use install::{install_contract, install_or_upgrade, upgrade_contract, ContractHooks};
+
+use constants::{
+    AMOUNT, DATA, ENABLE_MINT_BURN, FROM, ON_CEP18_RECEIVED_ENTRY_POINT_NAME, OPERATOR,
+};
+use events::{
+    Burn, BurnFrom, ChangeSecurity, DecreaseAllowance, Event, IncreaseAllowance, Mint,
+    ProposeAdmin, SetAllowance, Transfer, TransferFrom,
+};
+use utils::read_from;
+
+/// Moves `amount` tokens from `sender` to `recipient`. `sender` is taken as is, entry points
+/// pass the caller themselves, see [`utils::get_immediate_caller_address`].
+///
+/// Reverts if the contract is paused, if either account is frozen or cannot receive tokens under
+/// the transfer filter mode, or if `sender` does not hold enough tokens.
+pub fn transfer(sender: Key, recipient: Key, amount: U256) {
+    utils::ensure_not_paused();
+    if sender == recipient {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    ensure_not_frozen(&[sender, recipient]);
+
+    transfer_balance(sender, recipient, amount).unwrap_or_revert();
+    events::record_event_dictionary(Event::Transfer(Transfer {
+        sender,
+        recipient,
+        amount,
+    }))
+}
+
+/// Moves `amount` tokens of `owner` to `recipient` on behalf of `spender`, consuming the allowance
+/// `owner` granted `spender`.
+pub fn transfer_from(spender: Key, owner: Key, recipient: Key, amount: U256) {
+    utils::ensure_not_paused();
+    if owner == recipient {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    ensure_not_frozen(&[spender, owner, recipient]);
+    if amount.is_zero() {
+        return;
+    }
+
+    spend_allowance(owner, spender, amount).unwrap_or_revert();
+    transfer_balance(owner, recipient, amount).unwrap_or_revert();
+    events::record_event_dictionary(Event::TransferFrom(TransferFrom {
+        spender,
+        owner,
+        recipient,
+        amount,
+    }))
+}
+
+/// Moves `amount` tokens from `sender` to the contract package `recipient` as [`transfer`] does,
+/// then calls its `on_cep18_received` entry point with `data`.
+///
+/// Reverts if `recipient` is not a contract package or if it rejects the tokens.
+pub fn transfer_and_call(sender: Key, recipient: Key, amount: U256, data: Bytes) {
+    let recipient_package = recipient_package(recipient);
+    transfer(sender, recipient, amount);
+    notify_recipient(recipient_package, sender, sender, amount, data);
+}
+
+/// Moves `amount` tokens of `owner` to the contract package `recipient` on behalf of `spender` as
+/// [`transfer_from`] does, then calls its `on_cep18_received` entry point with `data`.
+pub fn transfer_from_and_call(spender: Key, owner: Key, recipient: Key, amount: U256, data: Bytes) {
+    let recipient_package = recipient_package(recipient);
+    transfer_from(spender, owner, recipient, amount);
+    notify_recipient(recipient_package, spender, owner, amount, data);
+}
+
+/// Contract package behind `recipient`. Contracts hold tokens under the key of their package, see
+/// [`utils::get_immediate_caller_address`].
+fn recipient_package(recipient: Key) -> ContractPackageHash {
+    match recipient {
+        Key::Hash(hash) => ContractPackageHash::new(hash),
+        _ => revert(Cep18Error::RecipientNotContract),
+    }
+}
+
+/// Tells `recipient` it received `amount` tokens of `from`, moved by `operator`. The recipient
+/// returns whether it accepts them, the whole transfer being reverted otherwise.
+fn notify_recipient(
+    recipient: ContractPackageHash,
+    operator: Key,
+    from: Key,
+    amount: U256,
+    data: Bytes,
+) {
+    let accepted: bool = runtime::call_versioned_contract(
+        recipient,
+        None,
+        ON_CEP18_RECEIVED_ENTRY_POINT_NAME,
+        runtime_args! {
+            OPERATOR => operator,
+            FROM => from,
+            AMOUNT => amount,
+            DATA => data,
+        },
+    );
+    if !accepted {
+        revert(Cep18Error::TransferRejected);
+    }
+}
+
+/// Sets the allowance of `spender` over the tokens of `owner` to `amount`, usable until
+/// `expires_at` (block time in milliseconds) if given.
+pub fn approve(owner: Key, spender: Key, amount: U256, expires_at: Option<u64>) {
+    utils::ensure_not_paused();
+    if spender == owner {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    ensure_not_frozen(&[owner, spender]);
+    write_allowance_to(get_allowances_uref(), owner, spender, amount);
+    write_allowance_expiration_to(get_allowance_expirations_uref(), owner, spender, expires_at);
+    events::record_event_dictionary(Event::SetAllowance(SetAllowance {
+        owner,
+        spender,
+        allowance: amount,
+    }))
+}
+
+/// Raises the allowance of `spender` over the tokens of `owner` by `amount`. Without
+/// `expires_at`, the current expiry is kept.
+pub fn increase_allowance(owner: Key, spender: Key, amount: U256, expires_at: Option<u64>) {
+    utils::ensure_not_paused();
+    if spender == owner {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    ensure_not_frozen(&[owner, spender]);
+    let expirations_uref = get_allowance_expirations_uref();
+    let expires_at = expires_at.or_else(|| {
+        // Keep the current expiry unless it already passed, the allowance then restarting from
+        // zero.
+        read_allowance_expiration_from(expirations_uref, owner, spender)
+            .filter(|expires_at| !has_expired(*expires_at))
+    });
+    let allowances_uref = get_allowances_uref();
+    let current_allowance = read_allowance_from(allowances_uref, owner, spender);
+    let new_allowance = current_allowance.saturating_add(amount);
+    write_allowance_to(allowances_uref, owner, spender, new_allowance);
+    write_allowance_expiration_to(expirations_uref, owner, spender, expires_at);
+    events::record_event_dictionary(Event::IncreaseAllowance(IncreaseAllowance {
+        owner,
+        spender,
+        allowance: new_allowance,
+        inc_by: amount,
+    }))
+}
+
+/// Lowers the allowance of `spender` over the tokens of `owner` by `amount`, down to zero.
+pub fn decrease_allowance(owner: Key, spender: Key, amount: U256) {
+    utils::ensure_not_paused();
+    if spender == owner {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    ensure_not_frozen(&[owner, spender]);
+    let allowances_uref = get_allowances_uref();
+    let current_allowance = read_allowance_from(allowances_uref, owner, spender);
+    let new_allowance = current_allowance.saturating_sub(amount);
+    write_allowance_to(allowances_uref, owner, spender, new_allowance);
+    events::record_event_dictionary(Event::DecreaseAllowance(DecreaseAllowance {
+        owner,
+        spender,
+        decr_by: amount,
+        allowance: new_allowance,
+    }))
+}
+
+/// Creates `amount` new tokens for `recipient`, within the maximum supply.
+///
+/// Does not check who asks for the tokens, custom entry points have to restrict it themselves,
+/// for instance through [`utils::sec_check`].
+pub fn mint(recipient: Key, amount: U256) {
+    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
+        revert(Cep18Error::MintBurnDisabled);
+    }
+    utils::ensure_not_paused();
+
+    mint_balances(&[(recipient, amount)]).unwrap_or_revert();
+    events::record_event_dictionary(Event::Mint(Mint { recipient, amount }))
+}
+
+/// Destroys `amount` tokens of `owner`.
+///
+/// Does not check who asks for it, custom entry points have to restrict it themselves.
+pub fn burn(owner: Key, amount: U256) {
+    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
+        revert(Cep18Error::MintBurnDisabled);
+    }
+    utils::ensure_not_paused();
+
+    burn_balance(owner, amount).unwrap_or_revert();
+    events::record_event_dictionary(Event::Burn(Burn { owner, amount }))
+}
+
+/// Destroys `amount` tokens of `owner` on behalf of `spender`, consuming the allowance `owner`
+/// granted `spender`.
+pub fn burn_from(spender: Key, owner: Key, amount: U256) {
+    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
+        revert(Cep18Error::MintBurnDisabled);
+    }
+    utils::ensure_not_paused();
+
+    if owner == spender {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    ensure_not_frozen(&[spender, owner]);
+    if amount.is_zero() {
+        return;
+    }
+
+    spend_allowance(owner, spender, amount).unwrap_or_revert();
+    burn_balance(owner, amount).unwrap_or_revert();
+    events::record_event_dictionary(Event::BurnFrom(BurnFrom {
+        spender,
+        owner,
+        amount,
+    }))
+}
+
+/// Gives the accounts of `badge_map` their badge on behalf of `admin`, which cannot change its own
+/// badge and is left out of the map. Reverts if it makes a new Admin, Admins being added through
+/// [`propose_admin`] and the `accept_admin` entry point only.
+///
+/// Does not check who asks for it, custom entry points have to restrict it themselves.
+pub fn change_security(admin: Key, mut badge_map: BTreeMap<Key, SecurityBadge>) {
+    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
+        revert(Cep18Error::MintBurnDisabled);
+    }
+    badge_map.remove(&admin);
+    utils::ensure_no_admin_grant(&badge_map);
+
+    utils::change_sec_badge(&badge_map);
+    events::record_event_dictionary(Event::ChangeSecurity(ChangeSecurity {
+        admin,
+        sec_change_map: badge_map,
+    }))
+}
+
+/// Proposes `candidate` as a new Admin on behalf of `admin`, replacing the pending proposal. The
+/// candidate becomes an Admin by calling the `accept_admin` entry point, unless `admin` loses its
+/// badge first.
+///
+/// Does not check who asks for it, custom entry points have to restrict it themselves.
+pub fn propose_admin(admin: Key, candidate: Key) {
+    utils::write_pending_admin(Some((candidate, admin)));
+    events::record_event_dictionary(Event::ProposeAdmin(ProposeAdmin { admin, candidate }))
+}
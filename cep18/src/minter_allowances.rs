@@ -0,0 +1,46 @@
+//! Implementation of the amount of tokens each minter can still mint.
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{Key, URef, U256};
+
+use crate::{balances::make_dictionary_item_key, constants::MINTER_ALLOWANCES, utils, Cep18Error};
+
+/// Getter for the "minter_allowances" dictionary URef.
+pub(crate) fn get_minter_allowances_uref() -> URef {
+    utils::get_uref(MINTER_ALLOWANCES)
+}
+
+/// Writes the amount a specified minter can still mint into a dictionary.
+pub(crate) fn write_minter_allowance_to(
+    minter_allowances_uref: URef,
+    minter: Key,
+    allowance: U256,
+) {
+    let dictionary_item_key = make_dictionary_item_key(minter);
+    storage::dictionary_put(minter_allowances_uref, &dictionary_item_key, allowance);
+}
+
+/// Reads the amount a specified minter can still mint.
+///
+/// Minters that were never given an allowance cannot mint.
+pub(crate) fn read_minter_allowance_from(minter_allowances_uref: URef, minter: Key) -> U256 {
+    let dictionary_item_key = make_dictionary_item_key(minter);
+
+    storage::dictionary_get(minter_allowances_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Whether setting `allowance` lets `minter` mint more than it currently can.
+pub(crate) fn raises_minter_allowance(minter: Key, allowance: U256) -> bool {
+    allowance > read_minter_allowance_from(get_minter_allowances_uref(), minter)
+}
+
+/// Consumes `amount` from the allowance of `minter`.
+pub(crate) fn spend_minter_allowance(minter: Key, amount: U256) -> Result<(), Cep18Error> {
+    let minter_allowances_uref = get_minter_allowances_uref();
+    let new_allowance = read_minter_allowance_from(minter_allowances_uref, minter)
+        .checked_sub(amount)
+        .ok_or(Cep18Error::MinterAllowanceExceeded)?;
+    write_minter_allowance_to(minter_allowances_uref, minter, new_allowance);
+    Ok(())
+}
@@ -1,43 +1,60 @@
 use core::convert::TryFrom;
 
+pub use cep18_types::{EventsMode, TransferFilterMode};
+
 use crate::Cep18Error;
 
 #[repr(u8)]
 #[derive(PartialEq, Eq)]
 #[allow(clippy::upper_case_acronyms)]
-pub enum EventsMode {
-    NoEvents = 0,
-    CES = 1,
+pub enum MintBurn {
+    Disabled = 0,
+    MintAndBurn = 1,
 }
 
-impl TryFrom<u8> for EventsMode {
+impl TryFrom<u8> for MintBurn {
     type Error = Cep18Error;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
-            0 => Ok(EventsMode::NoEvents),
-            1 => Ok(EventsMode::CES),
-            _ => Err(Cep18Error::InvalidEventsMode),
+            0 => Ok(MintBurn::Disabled),
+            1 => Ok(MintBurn::MintAndBurn),
+            _ => Err(Cep18Error::InvalidEnableMBFlag),
         }
     }
 }
 
 #[repr(u8)]
 #[derive(PartialEq, Eq)]
-#[allow(clippy::upper_case_acronyms)]
-pub enum MintBurn {
-    Disabled = 0,
-    MintAndBurn = 1,
+pub enum ProposalKind {
+    ChangeSecurity = 0,
+    Mint = 1,
+    Pause = 2,
+    Unpause = 3,
+    SetThreshold = 4,
+    SetMaxSupply = 5,
+    SetMinterAllowance = 6,
+    Upgrade = 7,
+    ProposeAdmin = 8,
+    CancelOperation = 9,
 }
 
-impl TryFrom<u8> for MintBurn {
+impl TryFrom<u8> for ProposalKind {
     type Error = Cep18Error;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
-            0 => Ok(MintBurn::Disabled),
-            1 => Ok(MintBurn::MintAndBurn),
-            _ => Err(Cep18Error::InvalidEnableMBFlag),
+            0 => Ok(ProposalKind::ChangeSecurity),
+            1 => Ok(ProposalKind::Mint),
+            2 => Ok(ProposalKind::Pause),
+            3 => Ok(ProposalKind::Unpause),
+            4 => Ok(ProposalKind::SetThreshold),
+            5 => Ok(ProposalKind::SetMaxSupply),
+            6 => Ok(ProposalKind::SetMinterAllowance),
+            7 => Ok(ProposalKind::Upgrade),
+            8 => Ok(ProposalKind::ProposeAdmin),
+            9 => Ok(ProposalKind::CancelOperation),
+            _ => Err(Cep18Error::InvalidProposalAction),
         }
     }
 }
@@ -0,0 +1,90 @@
+//! Implementation of signed approvals (permits).
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::ToBytes,
+    crypto::{self, PublicKey, Signature},
+    Key, URef, U256,
+};
+
+use crate::{
+    balances::make_dictionary_item_key,
+    constants::{NONCES, PACKAGE_HASH},
+    utils, Cep18Error,
+};
+
+/// Prefix of every permit message, so that a permit signature cannot be mistaken for a signature
+/// over anything else.
+const PERMIT_DOMAIN: &[u8] = b"CEP18_PERMIT";
+
+/// Getter for the "nonces" dictionary URef.
+pub(crate) fn get_nonces_uref() -> URef {
+    utils::get_uref(NONCES)
+}
+
+/// Writes the permit nonce of a specified account into a dictionary.
+pub(crate) fn write_nonce_to(nonces_uref: URef, owner: Key, nonce: u64) {
+    let dictionary_item_key = make_dictionary_item_key(owner);
+    storage::dictionary_put(nonces_uref, &dictionary_item_key, nonce);
+}
+
+/// Reads the permit nonce of a specified account.
+///
+/// Accounts that never used a permit have a nonce of 0.
+pub(crate) fn read_nonce_from(nonces_uref: URef, owner: Key) -> u64 {
+    let dictionary_item_key = make_dictionary_item_key(owner);
+
+    storage::dictionary_get(nonces_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Creates the message an owner signs to grant `spender` an allowance of `amount`.
+///
+/// The message is the blake2b hash of the serialized `PERMIT_DOMAIN`, contract package hash,
+/// owner, spender, amount, deadline and nonce, which binds the permit to this token.
+pub(crate) fn make_permit_message(
+    owner: Key,
+    spender: Key,
+    amount: U256,
+    deadline: u64,
+    nonce: u64,
+) -> [u8; 32] {
+    let package_hash =
+        runtime::get_key(PACKAGE_HASH).unwrap_or_revert_with(Cep18Error::PackageHashMissing);
+
+    let mut preimage = Vec::new();
+    preimage.extend_from_slice(PERMIT_DOMAIN);
+    preimage.append(&mut package_hash.to_bytes().unwrap_or_revert());
+    preimage.append(&mut owner.to_bytes().unwrap_or_revert());
+    preimage.append(&mut spender.to_bytes().unwrap_or_revert());
+    preimage.append(&mut amount.to_bytes().unwrap_or_revert());
+    preimage.append(&mut deadline.to_bytes().unwrap_or_revert());
+    preimage.append(&mut nonce.to_bytes().unwrap_or_revert());
+
+    runtime::blake2b(preimage)
+}
+
+/// Verifies a raw 64 bytes Ed25519 or Secp256k1 `signature` of `message` by `public_key`.
+pub(crate) fn verify_signature(
+    message: &[u8],
+    signature: &[u8],
+    public_key: &PublicKey,
+) -> Result<(), Cep18Error> {
+    let signature_bytes: [u8; 64] = signature
+        .try_into()
+        .map_err(|_| Cep18Error::InvalidSignature)?;
+    let signature = match public_key {
+        PublicKey::Ed25519(_) => Signature::ed25519(signature_bytes),
+        PublicKey::Secp256k1(_) => Signature::secp256k1(signature_bytes),
+        PublicKey::System => return Err(Cep18Error::InvalidSignature),
+    }
+    .map_err(|_| Cep18Error::InvalidSignature)?;
+
+    crypto::verify(message, &signature, public_key).map_err(|_| Cep18Error::InvalidSignature)
+}
@@ -0,0 +1,390 @@
+//! Implementation of Admin proposals, carried out once enough Admins approved them.
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+use core::convert::TryFrom;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
+    Key, U256,
+};
+
+use crate::{
+    balances::mint_balances,
+    constants::{
+        ACCOUNT, ACTION, AMOUNT, ENABLE_MINT_BURN, MAX_SUPPLY, OPERATION_ID, OWNER, PAUSED,
+        PROPOSALS, PROPOSAL_COUNT, PROPOSAL_THRESHOLD, THRESHOLD, TOTAL_SUPPLY, UPGRADE_READY,
+    },
+    events::{
+        self, ApproveProposal, CancelProposal, CreateProposal, Event, ExecuteProposal, Mint,
+        Paused, SetMinterAllowance, Unpaused,
+    },
+    minter_allowances::{get_minter_allowances_uref, write_minter_allowance_to},
+    modalities::ProposalKind,
+    roles, timelock, utils, Cep18Error,
+};
+use cep18_types::SecurityBadge;
+
+/// Admin action carried out once its proposal gathered enough approvals, or once its scheduled
+/// operation is past the timelock delay.
+#[derive(Clone)]
+pub(crate) enum ProposalAction {
+    ChangeSecurity(BTreeMap<Key, SecurityBadge>),
+    Mint { owner: Key, amount: U256 },
+    Pause,
+    Unpause,
+    SetThreshold(u64),
+    SetMaxSupply(U256),
+    SetMinterAllowance { minter: Key, allowance: U256 },
+    Upgrade,
+    ProposeAdmin(Key),
+    CancelOperation(u64),
+}
+
+impl ProposalAction {
+    pub(crate) fn kind(&self) -> ProposalKind {
+        match self {
+            ProposalAction::ChangeSecurity(_) => ProposalKind::ChangeSecurity,
+            ProposalAction::Mint { .. } => ProposalKind::Mint,
+            ProposalAction::Pause => ProposalKind::Pause,
+            ProposalAction::Unpause => ProposalKind::Unpause,
+            ProposalAction::SetThreshold(_) => ProposalKind::SetThreshold,
+            ProposalAction::SetMaxSupply(_) => ProposalKind::SetMaxSupply,
+            ProposalAction::SetMinterAllowance { .. } => ProposalKind::SetMinterAllowance,
+            ProposalAction::Upgrade => ProposalKind::Upgrade,
+            ProposalAction::ProposeAdmin(_) => ProposalKind::ProposeAdmin,
+            ProposalAction::CancelOperation(_) => ProposalKind::CancelOperation,
+        }
+    }
+}
+
+impl ToBytes for ProposalAction {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::with_capacity(self.serialized_length());
+        result.push(self.kind() as u8);
+        match self {
+            ProposalAction::ChangeSecurity(badge_map) => result.append(&mut badge_map.to_bytes()?),
+            ProposalAction::Mint { owner, amount } => {
+                result.append(&mut owner.to_bytes()?);
+                result.append(&mut amount.to_bytes()?);
+            }
+            ProposalAction::Pause | ProposalAction::Unpause | ProposalAction::Upgrade => {}
+            ProposalAction::SetThreshold(threshold) => result.append(&mut threshold.to_bytes()?),
+            ProposalAction::SetMaxSupply(max_supply) => result.append(&mut max_supply.to_bytes()?),
+            ProposalAction::ProposeAdmin(candidate) => result.append(&mut candidate.to_bytes()?),
+            ProposalAction::CancelOperation(id) => result.append(&mut id.to_bytes()?),
+            ProposalAction::SetMinterAllowance { minter, allowance } => {
+                result.append(&mut minter.to_bytes()?);
+                result.append(&mut allowance.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        1 + match self {
+            ProposalAction::ChangeSecurity(badge_map) => badge_map.serialized_length(),
+            ProposalAction::Mint { owner, amount } => {
+                owner.serialized_length() + amount.serialized_length()
+            }
+            ProposalAction::Pause | ProposalAction::Unpause | ProposalAction::Upgrade => 0,
+            ProposalAction::SetThreshold(threshold) => threshold.serialized_length(),
+            ProposalAction::SetMaxSupply(max_supply) => max_supply.serialized_length(),
+            ProposalAction::ProposeAdmin(candidate) => candidate.serialized_length(),
+            ProposalAction::CancelOperation(id) => id.serialized_length(),
+            ProposalAction::SetMinterAllowance { minter, allowance } => {
+                minter.serialized_length() + allowance.serialized_length()
+            }
+        }
+    }
+}
+
+impl FromBytes for ProposalAction {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (kind, remainder) = u8::from_bytes(bytes)?;
+        match ProposalKind::try_from(kind).map_err(|_| bytesrepr::Error::Formatting)? {
+            ProposalKind::ChangeSecurity => {
+                let (badge_map, remainder) = BTreeMap::from_bytes(remainder)?;
+                Ok((ProposalAction::ChangeSecurity(badge_map), remainder))
+            }
+            ProposalKind::Mint => {
+                let (owner, remainder) = Key::from_bytes(remainder)?;
+                let (amount, remainder) = U256::from_bytes(remainder)?;
+                Ok((ProposalAction::Mint { owner, amount }, remainder))
+            }
+            ProposalKind::Pause => Ok((ProposalAction::Pause, remainder)),
+            ProposalKind::Unpause => Ok((ProposalAction::Unpause, remainder)),
+            ProposalKind::SetThreshold => {
+                let (threshold, remainder) = u64::from_bytes(remainder)?;
+                Ok((ProposalAction::SetThreshold(threshold), remainder))
+            }
+            ProposalKind::SetMaxSupply => {
+                let (max_supply, remainder) = U256::from_bytes(remainder)?;
+                Ok((ProposalAction::SetMaxSupply(max_supply), remainder))
+            }
+            ProposalKind::SetMinterAllowance => {
+                let (minter, remainder) = Key::from_bytes(remainder)?;
+                let (allowance, remainder) = U256::from_bytes(remainder)?;
+                Ok((
+                    ProposalAction::SetMinterAllowance { minter, allowance },
+                    remainder,
+                ))
+            }
+            ProposalKind::Upgrade => Ok((ProposalAction::Upgrade, remainder)),
+            ProposalKind::ProposeAdmin => {
+                let (candidate, remainder) = Key::from_bytes(remainder)?;
+                Ok((ProposalAction::ProposeAdmin(candidate), remainder))
+            }
+            ProposalKind::CancelOperation => {
+                let (id, remainder) = u64::from_bytes(remainder)?;
+                Ok((ProposalAction::CancelOperation(id), remainder))
+            }
+        }
+    }
+}
+
+/// A proposed action along with the Admins that approved it, the first one being its proposer.
+pub(crate) struct Proposal {
+    action: ProposalAction,
+    approvals: Vec<Key>,
+    executed: bool,
+    cancelled: bool,
+}
+
+impl ToBytes for Proposal {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.action.to_bytes()?);
+        result.append(&mut self.approvals.to_bytes()?);
+        result.append(&mut self.executed.to_bytes()?);
+        result.append(&mut self.cancelled.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.action.serialized_length()
+            + self.approvals.serialized_length()
+            + self.executed.serialized_length()
+            + self.cancelled.serialized_length()
+    }
+}
+
+impl FromBytes for Proposal {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (action, remainder) = ProposalAction::from_bytes(bytes)?;
+        let (approvals, remainder) = Vec::<Key>::from_bytes(remainder)?;
+        let (executed, remainder) = bool::from_bytes(remainder)?;
+        let (cancelled, remainder) = bool::from_bytes(remainder)?;
+        Ok((
+            Proposal {
+                action,
+                approvals,
+                executed,
+                cancelled,
+            },
+            remainder,
+        ))
+    }
+}
+
+/// Builds the action picked through the `action` runtime argument from the arguments it needs.
+pub(crate) fn get_action_arg() -> ProposalAction {
+    let kind = ProposalKind::try_from(runtime::get_named_arg::<u8>(ACTION)).unwrap_or_revert();
+    if matches!(kind, ProposalKind::ChangeSecurity | ProposalKind::Mint)
+        && 0 == utils::read_from::<u8>(ENABLE_MINT_BURN)
+    {
+        runtime::revert(Cep18Error::MintBurnDisabled);
+    }
+    match kind {
+        ProposalKind::ChangeSecurity => {
+            let badge_map = utils::read_sec_badge_lists();
+            utils::ensure_no_admin_grant(&badge_map);
+            ProposalAction::ChangeSecurity(badge_map)
+        }
+        ProposalKind::Mint => ProposalAction::Mint {
+            owner: runtime::get_named_arg(OWNER),
+            amount: runtime::get_named_arg(AMOUNT),
+        },
+        ProposalKind::Pause => ProposalAction::Pause,
+        ProposalKind::Unpause => ProposalAction::Unpause,
+        ProposalKind::SetThreshold => {
+            ProposalAction::SetThreshold(runtime::get_named_arg(THRESHOLD))
+        }
+        ProposalKind::SetMaxSupply => {
+            ProposalAction::SetMaxSupply(runtime::get_named_arg(MAX_SUPPLY))
+        }
+        ProposalKind::SetMinterAllowance => ProposalAction::SetMinterAllowance {
+            minter: runtime::get_named_arg(ACCOUNT),
+            allowance: runtime::get_named_arg(AMOUNT),
+        },
+        ProposalKind::Upgrade => ProposalAction::Upgrade,
+        ProposalKind::ProposeAdmin => ProposalAction::ProposeAdmin(runtime::get_named_arg(ACCOUNT)),
+        ProposalKind::CancelOperation => {
+            ProposalAction::CancelOperation(runtime::get_named_arg(OPERATION_ID))
+        }
+    }
+}
+
+/// Reads the number of Admin approvals a proposal needs to be carried out.
+pub(crate) fn read_threshold() -> u64 {
+    utils::read_from(PROPOSAL_THRESHOLD)
+}
+
+/// Reverts if Admin actions have to go through proposals rather than being carried out by a
+/// single Admin, which is the case as soon as the threshold is above 1.
+pub(crate) fn ensure_no_proposal_required() {
+    if read_threshold() > 1 {
+        runtime::revert(Cep18Error::ProposalRequired);
+    }
+}
+
+/// Stores a new proposal approved by `proposer` and returns its id. The proposal is carried out
+/// right away if that approval is enough.
+pub(crate) fn create_proposal(proposer: Key, action: ProposalAction) -> Result<u64, Cep18Error> {
+    let proposal_count_uref = utils::get_uref(PROPOSAL_COUNT);
+    let id: u64 = storage::read(proposal_count_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    storage::write(proposal_count_uref, id + 1);
+
+    events::record_event_dictionary(Event::CreateProposal(CreateProposal {
+        admin: proposer,
+        id,
+        kind: action.kind() as u8,
+    }));
+    write_proposal(
+        id,
+        Proposal {
+            action,
+            approvals: Vec::new(),
+            executed: false,
+            cancelled: false,
+        },
+    );
+    approve_proposal(proposer, id)?;
+    Ok(id)
+}
+
+/// Records the approval of `approver`, carrying the proposal out once the Admins that approved it
+/// reach the threshold. Actions under timelock are scheduled instead.
+pub(crate) fn approve_proposal(approver: Key, id: u64) -> Result<(), Cep18Error> {
+    let mut proposal = read_pending_proposal(id)?;
+    if proposal.approvals.contains(&approver) {
+        return Err(Cep18Error::ProposalAlreadyApproved);
+    }
+    proposal.approvals.push(approver);
+    events::record_event_dictionary(Event::ApproveProposal(ApproveProposal {
+        admin: approver,
+        id,
+        approvals: proposal.approvals.len() as u64,
+    }));
+
+    // Approvals of accounts that lost their Admin badge since then do not count.
+    let admin_approvals = proposal
+        .approvals
+        .iter()
+        .filter(|account| utils::read_sec_badge(**account) == Some(SecurityBadge::Admin))
+        .count() as u64;
+    if admin_approvals >= read_threshold() {
+        proposal.executed = true;
+        if timelock::is_timelocked(&proposal.action) {
+            timelock::schedule(approver, proposal.action.clone());
+        } else {
+            execute(approver, &proposal.action)?;
+        }
+        events::record_event_dictionary(Event::ExecuteProposal(ExecuteProposal {
+            admin: approver,
+            id,
+        }));
+    }
+    write_proposal(id, proposal);
+    Ok(())
+}
+
+/// Drops a pending proposal so it can no longer be approved. Only its proposer can cancel it.
+pub(crate) fn cancel_proposal(admin: Key, id: u64) -> Result<(), Cep18Error> {
+    let mut proposal = read_pending_proposal(id)?;
+    if proposal.approvals.first() != Some(&admin) {
+        return Err(Cep18Error::NotProposer);
+    }
+    proposal.cancelled = true;
+    write_proposal(id, proposal);
+    events::record_event_dictionary(Event::CancelProposal(CancelProposal { admin, id }));
+    Ok(())
+}
+
+/// Carries `action` out on behalf of `executor`.
+pub(crate) fn execute(executor: Key, action: &ProposalAction) -> Result<(), Cep18Error> {
+    match action {
+        ProposalAction::ChangeSecurity(badge_map) => {
+            crate::change_security(executor, badge_map.clone())
+        }
+        ProposalAction::Mint { owner, amount } => {
+            utils::ensure_not_paused();
+            mint_balances(&[(*owner, *amount)])?;
+            events::record_event_dictionary(Event::Mint(Mint {
+                recipient: *owner,
+                amount: *amount,
+            }));
+        }
+        ProposalAction::Pause => {
+            storage::write(utils::get_uref(PAUSED), true);
+            events::record_event_dictionary(Event::Paused(Paused { admin: executor }));
+        }
+        ProposalAction::Unpause => {
+            storage::write(utils::get_uref(PAUSED), false);
+            events::record_event_dictionary(Event::Unpaused(Unpaused { admin: executor }));
+        }
+        ProposalAction::SetThreshold(threshold) => {
+            if *threshold == 0 || *threshold > roles::read_role_member_count(SecurityBadge::Admin) {
+                return Err(Cep18Error::InvalidProposalThreshold);
+            }
+            // A candidate proposed by a single Admin cannot join once proposals are required.
+            if read_threshold() == 1 && *threshold > 1 {
+                utils::write_pending_admin(None);
+            }
+            storage::write(utils::get_uref(PROPOSAL_THRESHOLD), *threshold);
+        }
+        ProposalAction::SetMaxSupply(max_supply) => {
+            if *max_supply < utils::read_from::<U256>(TOTAL_SUPPLY) {
+                return Err(Cep18Error::InvalidMaxSupply);
+            }
+            storage::write(utils::get_uref(MAX_SUPPLY), *max_supply);
+        }
+        ProposalAction::SetMinterAllowance { minter, allowance } => {
+            write_minter_allowance_to(get_minter_allowances_uref(), *minter, *allowance);
+            events::record_event_dictionary(Event::SetMinterAllowance(SetMinterAllowance {
+                admin: executor,
+                minter: *minter,
+                allowance: *allowance,
+            }));
+        }
+        // Lets the next contract version be added through `upgrade_contract`.
+        ProposalAction::Upgrade => storage::write(utils::get_uref(UPGRADE_READY), true),
+        ProposalAction::ProposeAdmin(candidate) => crate::propose_admin(executor, *candidate),
+        ProposalAction::CancelOperation(id) => timelock::cancel(executor, *id)?,
+    }
+    Ok(())
+}
+
+// Stored serialized, as `Bytes`, to keep a concrete `CLType` in the dictionary.
+fn write_proposal(id: u64, proposal: Proposal) {
+    let bytes = Bytes::from(proposal.to_bytes().unwrap_or_revert());
+    storage::dictionary_put(utils::get_uref(PROPOSALS), &id.to_string(), bytes);
+}
+
+fn read_pending_proposal(id: u64) -> Result<Proposal, Cep18Error> {
+    let bytes: Bytes = storage::dictionary_get(utils::get_uref(PROPOSALS), &id.to_string())
+        .unwrap_or_revert()
+        .ok_or(Cep18Error::ProposalNotFound)?;
+    let (proposal, _) = Proposal::from_bytes(&bytes).unwrap_or_revert();
+    if proposal.executed {
+        return Err(Cep18Error::ProposalAlreadyExecuted);
+    }
+    if proposal.cancelled {
+        return Err(Cep18Error::ProposalCancelled);
+    }
+    Ok(proposal)
+}
@@ -0,0 +1,105 @@
+//! Implementation of the enumerable member sets of each security badge.
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{Key, URef};
+
+use crate::{
+    balances::make_dictionary_item_key,
+    constants::{ROLE_MEMBERS, ROLE_MEMBER_COUNTS, ROLE_MEMBER_INDICES},
+    utils, Cep18Error,
+};
+use cep18_types::SecurityBadge;
+
+fn make_count_item_key(badge: SecurityBadge) -> String {
+    (badge as u8).to_string()
+}
+
+fn make_member_item_key(badge: SecurityBadge, index: u64) -> String {
+    format!("{}_{}", badge as u8, index)
+}
+
+/// Reads the number of accounts holding `badge`.
+pub(crate) fn read_role_member_count(badge: SecurityBadge) -> u64 {
+    storage::dictionary_get(
+        utils::get_uref(ROLE_MEMBER_COUNTS),
+        &make_count_item_key(badge),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_default()
+}
+
+/// Reads the account stored at `index` of the members of `badge`, in no particular order.
+pub(crate) fn read_role_member_at(badge: SecurityBadge, index: u64) -> Result<Key, Cep18Error> {
+    if index >= read_role_member_count(badge) {
+        return Err(Cep18Error::RoleMemberIndexOutOfRange);
+    }
+    storage::dictionary_get(
+        utils::get_uref(ROLE_MEMBERS),
+        &make_member_item_key(badge, index),
+    )
+    .unwrap_or_revert()
+    .ok_or(Cep18Error::RoleMemberIndexOutOfRange)
+}
+
+/// Moves `account` from the members of its previous badge to the members of `badge`.
+///
+/// `SecurityBadge::None` has no members. Removal moves the last member into the freed position so
+/// the set stays contiguous. Accounts given a badge before the sets existed are added the next
+/// time their badge changes, or through the `index_accounts` entry point.
+pub(crate) fn update_role_membership(account: Key, badge: SecurityBadge) {
+    let members_uref = utils::get_uref(ROLE_MEMBERS);
+    let counts_uref = utils::get_uref(ROLE_MEMBER_COUNTS);
+    let indices_uref = utils::get_uref(ROLE_MEMBER_INDICES);
+
+    let membership = read_membership_from(indices_uref, account);
+    if let Some((previous_badge, index)) = membership {
+        if previous_badge == badge {
+            return;
+        }
+        let last_index = read_role_member_count(previous_badge) - 1;
+        if index != last_index {
+            let last_member: Key = storage::dictionary_get(
+                members_uref,
+                &make_member_item_key(previous_badge, last_index),
+            )
+            .unwrap_or_revert()
+            .unwrap_or_revert_with(Cep18Error::RoleMemberIndexOutOfRange);
+            storage::dictionary_put(
+                members_uref,
+                &make_member_item_key(previous_badge, index),
+                last_member,
+            );
+            write_membership_to(indices_uref, last_member, Some((previous_badge, index)));
+        }
+        storage::dictionary_put(
+            counts_uref,
+            &make_count_item_key(previous_badge),
+            last_index,
+        );
+    }
+
+    if badge == SecurityBadge::None {
+        write_membership_to(indices_uref, account, None);
+        return;
+    }
+    let count = read_role_member_count(badge);
+    storage::dictionary_put(members_uref, &make_member_item_key(badge, count), account);
+    storage::dictionary_put(counts_uref, &make_count_item_key(badge), count + 1);
+    write_membership_to(indices_uref, account, Some((badge, count)));
+}
+
+fn write_membership_to(indices_uref: URef, account: Key, membership: Option<(SecurityBadge, u64)>) {
+    let dictionary_item_key = make_dictionary_item_key(account);
+    storage::dictionary_put(indices_uref, &dictionary_item_key, membership);
+}
+
+fn read_membership_from(indices_uref: URef, account: Key) -> Option<(SecurityBadge, u64)> {
+    let dictionary_item_key = make_dictionary_item_key(account);
+    storage::dictionary_get(indices_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .flatten()
+}
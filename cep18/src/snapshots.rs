@@ -0,0 +1,171 @@
+//! Implementation of balance and total supply snapshots.
+use alloc::string::{String, ToString};
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{Key, URef, U256};
+
+use crate::{
+    balances::{
+        get_balances_uref, make_dictionary_item_key, make_indexed_item_key, read_balance_from,
+    },
+    constants::{
+        BALANCE_SNAPSHOTS, BALANCE_SNAPSHOT_COUNTS, SNAPSHOT_ID, TOTAL_SUPPLY_SNAPSHOTS,
+        TOTAL_SUPPLY_SNAPSHOT_COUNT,
+    },
+    utils::{self, get_total_supply_uref, read_total_supply_from},
+    Cep18Error,
+};
+
+/// Snapshot id along with the value held when that snapshot was taken.
+///
+/// A value is only recorded when it first changes after a snapshot, so the value at a snapshot id
+/// is the one of the first entry with an id greater than or equal to it, or the current value.
+type Snapshot = (u64, U256);
+
+/// Entries of a balance or of the total supply, stored one per dictionary item by increasing index
+/// and snapshot id, along with their count.
+#[derive(Clone, Copy)]
+enum Snapshots {
+    Balance(Key),
+    TotalSupply,
+}
+
+impl Snapshots {
+    fn dictionary_uref(self) -> URef {
+        match self {
+            Snapshots::Balance(_) => utils::get_uref(BALANCE_SNAPSHOTS),
+            Snapshots::TotalSupply => utils::get_uref(TOTAL_SUPPLY_SNAPSHOTS),
+        }
+    }
+
+    fn item_key(self, index: u64) -> String {
+        match self {
+            Snapshots::Balance(address) => make_indexed_item_key(address, index),
+            Snapshots::TotalSupply => index.to_string(),
+        }
+    }
+
+    fn count(self) -> u64 {
+        match self {
+            Snapshots::Balance(address) => storage::dictionary_get(
+                utils::get_uref(BALANCE_SNAPSHOT_COUNTS),
+                &make_dictionary_item_key(address),
+            )
+            .unwrap_or_revert()
+            .unwrap_or_default(),
+            Snapshots::TotalSupply => utils::read_from(TOTAL_SUPPLY_SNAPSHOT_COUNT),
+        }
+    }
+
+    fn get(self, index: u64) -> Snapshot {
+        storage::dictionary_get(self.dictionary_uref(), &self.item_key(index))
+            .unwrap_or_revert()
+            .unwrap_or_revert()
+    }
+
+    fn push(self, count: u64, snapshot: Snapshot) {
+        storage::dictionary_put(self.dictionary_uref(), &self.item_key(count), snapshot);
+        match self {
+            Snapshots::Balance(address) => storage::dictionary_put(
+                utils::get_uref(BALANCE_SNAPSHOT_COUNTS),
+                &make_dictionary_item_key(address),
+                count + 1,
+            ),
+            Snapshots::TotalSupply => {
+                storage::write(utils::get_uref(TOTAL_SUPPLY_SNAPSHOT_COUNT), count + 1)
+            }
+        }
+    }
+
+    /// Records the value returned by `read_value` for the current snapshot if it was not recorded
+    /// yet.
+    fn update(self, read_value: impl FnOnce() -> U256) {
+        let current_snapshot_id = read_current_snapshot_id();
+        if current_snapshot_id == 0 {
+            return;
+        }
+        let count = self.count();
+        if let Some(last_index) = count.checked_sub(1) {
+            if self.get(last_index).0 >= current_snapshot_id {
+                return;
+            }
+        }
+        self.push(count, (current_snapshot_id, read_value()));
+    }
+
+    /// Binary searches the first entry recorded at or after `snapshot_id`, reading one dictionary
+    /// item per step. `None` means the value did not change since.
+    fn value_at(self, snapshot_id: u64) -> Option<U256> {
+        let count = self.count();
+        let (mut low, mut high) = (0, count);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if self.get(middle).0 < snapshot_id {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+        if low < count {
+            Some(self.get(low).1)
+        } else {
+            None
+        }
+    }
+}
+
+/// Reads the id of the latest snapshot, 0 meaning no snapshot was taken yet.
+pub(crate) fn read_current_snapshot_id() -> u64 {
+    utils::read_from(SNAPSHOT_ID)
+}
+
+/// Takes a new snapshot and returns its id.
+pub(crate) fn take_snapshot() -> u64 {
+    let snapshot_id_uref = utils::get_uref(SNAPSHOT_ID);
+    let snapshot_id: u64 = storage::read(snapshot_id_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    let new_snapshot_id = snapshot_id
+        .checked_add(1)
+        .unwrap_or_revert_with(Cep18Error::Overflow);
+    storage::write(snapshot_id_uref, new_snapshot_id);
+    new_snapshot_id
+}
+
+/// Records the balance of `address` for the current snapshot if it was not recorded yet.
+///
+/// Must be called before the balance changes, `read_balance` returning the balance to record.
+pub(crate) fn update_balance_snapshot(address: Key, read_balance: impl FnOnce() -> U256) {
+    Snapshots::Balance(address).update(read_balance);
+}
+
+/// Records the total supply for the current snapshot if it was not recorded yet.
+///
+/// Must be called before the total supply changes, `read_total_supply` returning the total supply
+/// to record.
+pub(crate) fn update_total_supply_snapshot(read_total_supply: impl FnOnce() -> U256) {
+    Snapshots::TotalSupply.update(read_total_supply);
+}
+
+/// Reads the balance of `address` at the time snapshot `snapshot_id` was taken.
+pub(crate) fn read_balance_at(address: Key, snapshot_id: u64) -> Result<U256, Cep18Error> {
+    check_snapshot_id(snapshot_id)?;
+    Ok(Snapshots::Balance(address)
+        .value_at(snapshot_id)
+        .unwrap_or_else(|| read_balance_from(get_balances_uref(), address)))
+}
+
+/// Reads the total supply at the time snapshot `snapshot_id` was taken.
+pub(crate) fn read_total_supply_at(snapshot_id: u64) -> Result<U256, Cep18Error> {
+    check_snapshot_id(snapshot_id)?;
+    Ok(Snapshots::TotalSupply
+        .value_at(snapshot_id)
+        .unwrap_or_else(|| read_total_supply_from(get_total_supply_uref())))
+}
+
+fn check_snapshot_id(snapshot_id: u64) -> Result<(), Cep18Error> {
+    if snapshot_id == 0 || snapshot_id > read_current_snapshot_id() {
+        return Err(Cep18Error::InvalidSnapshotId);
+    }
+    Ok(())
+}
@@ -0,0 +1,187 @@
+//! Implementation of operations that Admins schedule and can only execute once the timelock delay
+//! has passed, giving token holders time to react.
+//!
+//! Upgrades go through an `Upgrade` operation, checked by [`crate::upgrade_contract`].
+use alloc::{string::ToString, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
+    ContractHash, Key,
+};
+
+use crate::{
+    constants::{MIGRATED_CONTRACT, OPERATIONS, OPERATION_COUNT, TIMELOCK_DELAY, UPGRADE_READY},
+    events::{self, CancelOperation, Event, ExecuteOperation, ScheduleOperation},
+    minter_allowances,
+    proposals::{self, ProposalAction},
+    utils, Cep18Error,
+};
+
+/// An action along with the block time from which it can be executed.
+pub(crate) struct Operation {
+    action: ProposalAction,
+    ready_at: u64,
+    executed: bool,
+    cancelled: bool,
+}
+
+impl ToBytes for Operation {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.action.to_bytes()?);
+        result.append(&mut self.ready_at.to_bytes()?);
+        result.append(&mut self.executed.to_bytes()?);
+        result.append(&mut self.cancelled.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.action.serialized_length()
+            + self.ready_at.serialized_length()
+            + self.executed.serialized_length()
+            + self.cancelled.serialized_length()
+    }
+}
+
+impl FromBytes for Operation {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (action, remainder) = ProposalAction::from_bytes(bytes)?;
+        let (ready_at, remainder) = u64::from_bytes(remainder)?;
+        let (executed, remainder) = bool::from_bytes(remainder)?;
+        let (cancelled, remainder) = bool::from_bytes(remainder)?;
+        Ok((
+            Operation {
+                action,
+                ready_at,
+                executed,
+                cancelled,
+            },
+            remainder,
+        ))
+    }
+}
+
+/// Reads the delay, in milliseconds, between scheduling an operation and being able to execute it.
+pub(crate) fn read_delay() -> u64 {
+    utils::read_from(TIMELOCK_DELAY)
+}
+
+/// Whether `action` has to be scheduled before it can be carried out.
+pub(crate) fn is_timelocked(action: &ProposalAction) -> bool {
+    if read_delay() == 0 {
+        return false;
+    }
+    match action {
+        ProposalAction::ChangeSecurity(_)
+        | ProposalAction::Unpause
+        | ProposalAction::SetMaxSupply(_)
+        | ProposalAction::Upgrade
+        | ProposalAction::ProposeAdmin(_) => true,
+        // Lowering the threshold would let fewer Admins mint or run proposals right away, raising
+        // it only makes them stricter.
+        ProposalAction::SetThreshold(threshold) => *threshold < proposals::read_threshold(),
+        // Lowering a minter allowance is how a compromised minter gets stopped, so only raising it
+        // waits for the delay.
+        ProposalAction::SetMinterAllowance { minter, allowance } => {
+            minter_allowances::raises_minter_allowance(*minter, *allowance)
+        }
+        // Cancelling only stops an operation that is still waiting for its delay.
+        ProposalAction::Mint { .. }
+        | ProposalAction::Pause
+        | ProposalAction::CancelOperation(_) => false,
+    }
+}
+
+/// Reverts if sensitive Admin actions have to be scheduled rather than carried out right away.
+pub(crate) fn ensure_no_timelock_required() {
+    if read_delay() > 0 {
+        runtime::revert(Cep18Error::TimelockRequired);
+    }
+}
+
+/// Stores a new operation that can be executed once the delay has passed and returns its id.
+pub(crate) fn schedule(admin: Key, action: ProposalAction) -> u64 {
+    let operation_count_uref = utils::get_uref(OPERATION_COUNT);
+    let id: u64 = storage::read(operation_count_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    storage::write(operation_count_uref, id + 1);
+
+    let blocktime: u64 = runtime::get_blocktime().into();
+    let ready_at = blocktime.saturating_add(read_delay());
+    events::record_event_dictionary(Event::ScheduleOperation(ScheduleOperation {
+        admin,
+        id,
+        kind: action.kind() as u8,
+        ready_at,
+    }));
+    write_operation(
+        id,
+        Operation {
+            action,
+            ready_at,
+            executed: false,
+            cancelled: false,
+        },
+    );
+    id
+}
+
+/// Carries out a pending operation whose delay has passed.
+pub(crate) fn execute(admin: Key, id: u64) -> Result<(), Cep18Error> {
+    let mut operation = read_pending_operation(id)?;
+    let blocktime: u64 = runtime::get_blocktime().into();
+    if blocktime < operation.ready_at {
+        return Err(Cep18Error::OperationNotReady);
+    }
+    proposals::execute(admin, &operation.action)?;
+    operation.executed = true;
+    write_operation(id, operation);
+    events::record_event_dictionary(Event::ExecuteOperation(ExecuteOperation { admin, id }));
+    Ok(())
+}
+
+/// Drops a pending operation so it can no longer be executed.
+pub(crate) fn cancel(admin: Key, id: u64) -> Result<(), Cep18Error> {
+    let mut operation = read_pending_operation(id)?;
+    operation.cancelled = true;
+    write_operation(id, operation);
+    events::record_event_dictionary(Event::CancelOperation(CancelOperation { admin, id }));
+    Ok(())
+}
+
+/// Called by `migrate` so the first migration of a new contract version, which follows its
+/// upgrade, spends the executed `Upgrade` operation.
+pub(crate) fn record_migration() {
+    let contract_hash = utils::get_current_contract_hash();
+    let migrated_contract_uref = utils::get_uref(MIGRATED_CONTRACT);
+    let migrated_contract: ContractHash = storage::read(migrated_contract_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    if migrated_contract == contract_hash {
+        return;
+    }
+    storage::write(migrated_contract_uref, contract_hash);
+    storage::write(utils::get_uref(UPGRADE_READY), read_delay() == 0);
+}
+
+// Stored serialized, as proposals are.
+fn write_operation(id: u64, operation: Operation) {
+    let bytes = Bytes::from(operation.to_bytes().unwrap_or_revert());
+    storage::dictionary_put(utils::get_uref(OPERATIONS), &id.to_string(), bytes);
+}
+
+fn read_pending_operation(id: u64) -> Result<Operation, Cep18Error> {
+    let bytes: Bytes = storage::dictionary_get(utils::get_uref(OPERATIONS), &id.to_string())
+        .unwrap_or_revert()
+        .ok_or(Cep18Error::OperationNotFound)?;
+    let (operation, _) = Operation::from_bytes(&bytes).unwrap_or_revert();
+    if operation.executed || operation.cancelled {
+        return Err(Cep18Error::OperationNotPending);
+    }
+    Ok(operation)
+}
@@ -1,7 +1,7 @@
 //! Implementation details.
 use core::convert::TryInto;
 
-use alloc::{collections::BTreeMap, vec, vec::Vec};
+use alloc::{collections::BTreeMap, vec::Vec};
 use casper_contract::{
     contract_api::{
         self,
@@ -15,16 +15,20 @@ use casper_types::{
     api_error,
     bytesrepr::{self, FromBytes, ToBytes},
     system::CallStackElement,
-    ApiError, CLTyped, Key, URef, U256,
+    ApiError, CLTyped, ContractHash, Key, URef, U256,
 };
+use cep18_types::SecurityBadge;
 
 use crate::{
-    constants::{SECURITY_BADGES, TOTAL_SUPPLY},
-    error::Cep18Error,
+    constants::{
+        ADMIN_LIST, BURNER_LIST, KYC_ADMIN_LIST, MINTER_LIST, MINT_AND_BURN_LIST, NONE_LIST,
+        PAUSED, PENDING_ADMIN, SECURITY_BADGES, TOTAL_SUPPLY,
+    },
+    proposals, roles, snapshots, Cep18Error,
 };
 
 /// Gets [`URef`] under a name.
-pub(crate) fn get_uref(name: &str) -> URef {
+pub fn get_uref(name: &str) -> URef {
     let key = runtime::get_key(name)
         .ok_or(ApiError::MissingKey)
         .unwrap_or_revert();
@@ -32,7 +36,7 @@ pub(crate) fn get_uref(name: &str) -> URef {
 }
 
 /// Reads value from a named key.
-pub(crate) fn read_from<T>(name: &str) -> T
+pub fn read_from<T>(name: &str) -> T
 where
     T: FromBytes + CLTyped,
 {
@@ -63,7 +67,7 @@ fn call_stack_element_to_address(call_stack_element: CallStackElement) -> Key {
 /// Gets the immediate session caller of the current execution.
 ///
 /// This function ensures that Contracts can participate and no middleman (contract) acts for users.
-pub(crate) fn get_immediate_caller_address() -> Result<Key, Cep18Error> {
+pub fn get_immediate_caller_address() -> Result<Key, Cep18Error> {
     let call_stack = runtime::get_call_stack();
     call_stack
         .into_iter()
@@ -73,6 +77,14 @@ pub(crate) fn get_immediate_caller_address() -> Result<Key, Cep18Error> {
         .ok_or(Cep18Error::InvalidContext)
 }
 
+/// Gets the hash of the contract version being executed.
+pub(crate) fn get_current_contract_hash() -> ContractHash {
+    match runtime::get_call_stack().last() {
+        Some(CallStackElement::StoredContract { contract_hash, .. }) => *contract_hash,
+        _ => revert(Cep18Error::InvalidContext),
+    }
+}
+
 pub fn get_total_supply_uref() -> URef {
     get_uref(TOTAL_SUPPLY)
 }
@@ -81,11 +93,20 @@ pub(crate) fn read_total_supply_from(uref: URef) -> U256 {
     storage::read(uref).unwrap_or_revert().unwrap_or_revert()
 }
 
-/// Writes a total supply to a specific [`URef`].
+/// Writes a total supply to a specific [`URef`], recording the previous one for the current
+/// snapshot.
 pub(crate) fn write_total_supply_to(uref: URef, value: U256) {
+    snapshots::update_total_supply_snapshot(|| read_total_supply_from(uref));
     storage::write(uref, value);
 }
 
+/// Reverts if the contract is paused.
+pub fn ensure_not_paused() {
+    if read_from::<bool>(PAUSED) {
+        revert(Cep18Error::Paused);
+    }
+}
+
 pub fn get_named_arg_size(name: &str) -> Option<usize> {
     let mut arg_size: usize = 0;
     let ret = unsafe {
@@ -143,58 +164,94 @@ pub fn get_named_arg_with_user_errors<T: FromBytes>(
     bytesrepr::deserialize(arg_bytes).map_err(|_| invalid)
 }
 
-#[repr(u8)]
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub enum SecurityBadge {
-    Admin = 0,
-    Minter = 1,
-    None = 2,
+/// Reads the security badge held by `account`, if any.
+pub fn read_sec_badge(account: Key) -> Option<SecurityBadge> {
+    dictionary_get::<SecurityBadge>(
+        get_uref(SECURITY_BADGES),
+        &base64::encode(account.to_bytes().unwrap_or_revert()),
+    )
+    .unwrap_or_revert()
 }
 
-impl CLTyped for SecurityBadge {
-    fn cl_type() -> casper_types::CLType {
-        casper_types::CLType::U8
+pub fn sec_check(allowed_badge_list: Vec<SecurityBadge>) {
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    if !allowed_badge_list
+        .contains(&read_sec_badge(caller).unwrap_or_revert_with(Cep18Error::InsufficientRights))
+    {
+        revert(Cep18Error::InsufficientRights)
     }
 }
 
-impl ToBytes for SecurityBadge {
-    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
-        Ok(vec![*self as u8])
-    }
+/// Collects the optional badge list arguments into the badge each listed account will be given.
+///
+/// Lists are applied by increasing change strength (None > Admin > KycAdmin > MintAndBurn >
+/// Minter > Burner), so an account present in several lists ends up with the strongest badge.
+pub(crate) fn read_sec_badge_lists() -> BTreeMap<Key, SecurityBadge> {
+    let badge_lists = [
+        (
+            BURNER_LIST,
+            SecurityBadge::Burner,
+            Cep18Error::InvalidBurnerList,
+        ),
+        (
+            MINTER_LIST,
+            SecurityBadge::Minter,
+            Cep18Error::InvalidMinterList,
+        ),
+        (
+            MINT_AND_BURN_LIST,
+            SecurityBadge::MintAndBurn,
+            Cep18Error::InvalidMintAndBurnList,
+        ),
+        (
+            KYC_ADMIN_LIST,
+            SecurityBadge::KycAdmin,
+            Cep18Error::InvalidKycAdminList,
+        ),
+        (
+            ADMIN_LIST,
+            SecurityBadge::Admin,
+            Cep18Error::InvalidAdminList,
+        ),
+        (NONE_LIST, SecurityBadge::None, Cep18Error::InvalidNoneList),
+    ];
 
-    fn serialized_length(&self) -> usize {
-        1
+    let mut badge_map: BTreeMap<Key, SecurityBadge> = BTreeMap::new();
+    for (arg_name, badge, invalid) in badge_lists {
+        let account_list: Option<Vec<Key>> =
+            get_optional_named_arg_with_user_errors(arg_name, invalid);
+        for account_key in account_list.unwrap_or_default() {
+            badge_map.insert(account_key, badge);
+        }
     }
+    badge_map
 }
 
-impl FromBytes for SecurityBadge {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        Ok((
-            match bytes[0] {
-                0 => SecurityBadge::Admin,
-                1 => SecurityBadge::Minter,
-                2 => SecurityBadge::None,
-                _ => return Err(bytesrepr::Error::LeftOverBytes),
-            },
-            &[],
-        ))
+/// Reverts if `badge_map` makes Admin an account that is not one yet.
+pub(crate) fn ensure_no_admin_grant(badge_map: &BTreeMap<Key, SecurityBadge>) {
+    let grants_admin = badge_map.iter().any(|(&account, &badge)| {
+        badge == SecurityBadge::Admin && read_sec_badge(account) != Some(SecurityBadge::Admin)
+    });
+    if grants_admin {
+        revert(Cep18Error::AdminGrantNotAllowed);
     }
 }
 
-pub fn sec_check(allowed_badge_list: Vec<SecurityBadge>) {
-    let caller = get_immediate_caller_address()
-        .unwrap_or_revert()
-        .to_bytes()
-        .unwrap_or_revert();
-    if !allowed_badge_list.contains(
-        &dictionary_get::<SecurityBadge>(get_uref(SECURITY_BADGES), &base64::encode(caller))
-            .unwrap_or_revert()
-            .unwrap_or_revert_with(Cep18Error::InsufficientRights),
-    ) {
-        revert(Cep18Error::InsufficientRights)
-    }
+/// Reads the account proposed as a new Admin along with the Admin that proposed it, if any.
+pub(crate) fn read_pending_admin() -> Option<(Key, Key)> {
+    read_from(PENDING_ADMIN)
 }
 
+pub(crate) fn write_pending_admin(pending_admin: Option<(Key, Key)>) {
+    storage::write(get_uref(PENDING_ADMIN), pending_admin);
+}
+
+/// Applies `badge_map`, keeping track of the members of each badge. The pending Admin candidate is
+/// dropped if the Admin that proposed it loses its badge.
+///
+/// Reverts if the change would leave the contract without any Admin, or with fewer Admins than
+/// needed to approve a proposal. Admins are counted through the members of their badge, so Admins
+/// of previous versions only count once listed through `index_accounts`.
 pub fn change_sec_badge(badge_map: &BTreeMap<Key, SecurityBadge>) {
     let sec_uref = get_uref(SECURITY_BADGES);
     for (&user, &badge) in badge_map {
@@ -202,6 +259,20 @@ pub fn change_sec_badge(badge_map: &BTreeMap<Key, SecurityBadge>) {
             sec_uref,
             &base64::encode(user.to_bytes().unwrap_or_revert()),
             badge,
-        )
+        );
+        roles::update_role_membership(user, badge);
+    }
+    let admin_count = roles::read_role_member_count(SecurityBadge::Admin);
+    if admin_count == 0 {
+        revert(Cep18Error::LastAdmin);
+    }
+    if admin_count < proposals::read_threshold() {
+        revert(Cep18Error::InvalidProposalThreshold);
+    }
+
+    if let Some((_, proposer)) = read_pending_admin() {
+        if read_sec_badge(proposer) != Some(SecurityBadge::Admin) {
+            write_pending_admin(None);
+        }
     }
 }
@@ -0,0 +1,163 @@
+//! Implementation of vote delegation and checkpointed voting power.
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, URef, U256};
+
+use crate::{
+    balances::{
+        get_balances_uref, make_dictionary_item_key, make_indexed_item_key, read_balance_from,
+    },
+    constants::{DELEGATES, VOTE_CHECKPOINTS, VOTE_CHECKPOINT_COUNTS},
+    events::{self, DelegateChanged, DelegateVotesChanged, Event},
+    utils, Cep18Error,
+};
+
+/// Block time along with the voting power of a delegatee from that time on. The checkpoints of a
+/// delegatee are stored one per dictionary item, by increasing index and time.
+type Checkpoint = (u64, U256);
+
+/// Reads the account `delegator` delegates its voting power to, if any.
+pub(crate) fn read_delegate_of(delegator: Key) -> Option<Key> {
+    let dictionary_item_key = make_dictionary_item_key(delegator);
+    storage::dictionary_get(utils::get_uref(DELEGATES), &dictionary_item_key)
+        .unwrap_or_revert()
+        .flatten()
+}
+
+/// Delegates the voting power of `delegator` to `delegatee`, moving the votes of its current
+/// balance along.
+pub(crate) fn delegate(delegator: Key, delegatee: Key) {
+    let previous_delegatee = read_delegate_of(delegator);
+    if previous_delegatee == Some(delegatee) {
+        return;
+    }
+    let dictionary_item_key = make_dictionary_item_key(delegator);
+    storage::dictionary_put(
+        utils::get_uref(DELEGATES),
+        &dictionary_item_key,
+        Some(delegatee),
+    );
+    events::record_event_dictionary(Event::DelegateChanged(DelegateChanged {
+        delegator,
+        from_delegate: previous_delegatee,
+        to_delegate: delegatee,
+    }));
+
+    let balance = read_balance_from(get_balances_uref(), delegator);
+    if balance.is_zero() {
+        return;
+    }
+    if let Some(previous_delegatee) = previous_delegatee {
+        update_votes(previous_delegatee, |votes| votes.checked_sub(balance));
+    }
+    update_votes(delegatee, |votes| votes.checked_add(balance));
+}
+
+/// Moves the voting power of `address` along with its balance changing to `new_balance`.
+///
+/// Must be called before the balance changes, `read_balance` returning the previous balance.
+pub(crate) fn move_voting_power(
+    address: Key,
+    read_balance: impl FnOnce() -> U256,
+    new_balance: U256,
+) {
+    let delegatee = match read_delegate_of(address) {
+        Some(delegatee) => delegatee,
+        None => return,
+    };
+    let previous_balance = read_balance();
+    if previous_balance == new_balance {
+        return;
+    }
+    update_votes(delegatee, |votes| {
+        votes
+            .checked_sub(previous_balance)
+            .and_then(|votes| votes.checked_add(new_balance))
+    });
+}
+
+/// Reads the current voting power of `account`.
+pub(crate) fn read_votes(account: Key) -> U256 {
+    let checkpoints_uref = utils::get_uref(VOTE_CHECKPOINTS);
+    read_checkpoint_count(account)
+        .checked_sub(1)
+        .map(|index| read_checkpoint_from(checkpoints_uref, account, index).1)
+        .unwrap_or_default()
+}
+
+/// Reads the voting power of `account` at block time `timestamp`, which must be in the past.
+///
+/// Binary searches the checkpoints of `account`, reading one dictionary item per step.
+pub(crate) fn read_past_votes(account: Key, timestamp: u64) -> Result<U256, Cep18Error> {
+    if timestamp >= u64::from(runtime::get_blocktime()) {
+        return Err(Cep18Error::FutureLookup);
+    }
+    let checkpoints_uref = utils::get_uref(VOTE_CHECKPOINTS);
+    // Number of checkpoints taken at or before `timestamp`.
+    let (mut low, mut high) = (0, read_checkpoint_count(account));
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if read_checkpoint_from(checkpoints_uref, account, middle).0 <= timestamp {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    Ok(low
+        .checked_sub(1)
+        .map(|index| read_checkpoint_from(checkpoints_uref, account, index).1)
+        .unwrap_or_default())
+}
+
+fn read_checkpoint_count(account: Key) -> u64 {
+    let dictionary_item_key = make_dictionary_item_key(account);
+    storage::dictionary_get(
+        utils::get_uref(VOTE_CHECKPOINT_COUNTS),
+        &dictionary_item_key,
+    )
+    .unwrap_or_revert()
+    .unwrap_or_default()
+}
+
+fn read_checkpoint_from(checkpoints_uref: URef, account: Key, index: u64) -> Checkpoint {
+    storage::dictionary_get(checkpoints_uref, &make_indexed_item_key(account, index))
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+fn update_votes(delegatee: Key, update: impl FnOnce(U256) -> Option<U256>) {
+    let checkpoints_uref = utils::get_uref(VOTE_CHECKPOINTS);
+    let count = read_checkpoint_count(delegatee);
+    let last_checkpoint = count
+        .checked_sub(1)
+        .map(|index| read_checkpoint_from(checkpoints_uref, delegatee, index));
+    let previous_votes = last_checkpoint.map(|(_, votes)| votes).unwrap_or_default();
+    let new_votes = update(previous_votes).unwrap_or_revert_with(Cep18Error::Overflow);
+
+    // Changes within the same block overwrite its checkpoint.
+    let now = u64::from(runtime::get_blocktime());
+    let index = match last_checkpoint {
+        Some((time, _)) if time == now => count - 1,
+        _ => {
+            storage::dictionary_put(
+                utils::get_uref(VOTE_CHECKPOINT_COUNTS),
+                &make_dictionary_item_key(delegatee),
+                count + 1,
+            );
+            count
+        }
+    };
+    storage::dictionary_put(
+        checkpoints_uref,
+        &make_indexed_item_key(delegatee, index),
+        (now, new_votes),
+    );
+
+    events::record_event_dictionary(Event::DelegateVotesChanged(DelegateVotesChanged {
+        delegate: delegatee,
+        previous_votes,
+        new_votes,
+    }));
+}
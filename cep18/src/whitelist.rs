@@ -0,0 +1,49 @@
+//! Implementation of the whitelist used by the allowlist transfer filter mode.
+use core::convert::TryFrom;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{Key, URef};
+
+use crate::{
+    balances::make_dictionary_item_key,
+    constants::{TRANSFER_FILTER_MODE, WHITELIST},
+    modalities::TransferFilterMode,
+    utils, Cep18Error,
+};
+
+/// Getter for the "whitelist" dictionary URef.
+pub(crate) fn get_whitelist_uref() -> URef {
+    utils::get_uref(WHITELIST)
+}
+
+/// Writes the whitelisted status of a specified account into a dictionary.
+pub(crate) fn write_whitelisted_to(whitelist_uref: URef, address: Key, whitelisted: bool) {
+    let dictionary_item_key = make_dictionary_item_key(address);
+    storage::dictionary_put(whitelist_uref, &dictionary_item_key, whitelisted);
+}
+
+/// Reads the whitelisted status of a specified account.
+pub(crate) fn read_whitelisted_from(whitelist_uref: URef, address: Key) -> bool {
+    let dictionary_item_key = make_dictionary_item_key(address);
+
+    storage::dictionary_get(whitelist_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Checks that `recipient` can be credited tokens under the configured transfer filter mode.
+pub(crate) fn check_recipient(recipient: Key) -> Result<(), Cep18Error> {
+    let transfer_filter_mode =
+        TransferFilterMode::try_from(utils::read_from::<u8>(TRANSFER_FILTER_MODE))?;
+
+    match transfer_filter_mode {
+        TransferFilterMode::None => Ok(()),
+        TransferFilterMode::Allowlist => {
+            if read_whitelisted_from(get_whitelist_uref(), recipient) {
+                Ok(())
+            } else {
+                Err(Cep18Error::RecipientNotWhitelisted)
+            }
+        }
+    }
+}
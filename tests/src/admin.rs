@@ -0,0 +1,711 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_types::{
+    bytesrepr::FromBytes, runtime_args, ApiError, CLTyped, ContractHash, Key, RuntimeArgs, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ADMIN_LIST, ARG_ACCOUNT, ARG_ACCOUNTS, ARG_ACTION,
+        ARG_BADGE, ARG_DECIMALS, ARG_INDEX, ARG_NAME, ARG_OPERATION_ID, ARG_PROPOSAL_ID,
+        ARG_SYMBOL, ARG_THRESHOLD, ARG_TOKEN_CONTRACT, ARG_TOTAL_SUPPLY,
+        CEP18_BASELINE_CONTRACT_WASM, CEP18_CONTRACT_WASM, CEP18_TOKEN_CONTRACT_KEY,
+        CHANGE_SECURITY, CHECK_ROLE_MEMBER_AT_ENTRYPOINT, ENABLE_MINT_BURN,
+        ERROR_ADMIN_GRANT_NOT_ALLOWED, ERROR_INVALID_PROPOSAL_THRESHOLD, ERROR_LAST_ADMIN,
+        ERROR_NOT_PENDING_ADMIN, ERROR_PROPOSAL_REQUIRED, ERROR_ROLE_MEMBER_INDEX_OUT_OF_RANGE,
+        METHOD_ACCEPT_ADMIN, METHOD_APPROVE_PROPOSAL, METHOD_EXECUTE, METHOD_INDEX_ACCOUNTS,
+        METHOD_PAUSE, METHOD_PROPOSE, METHOD_PROPOSE_ADMIN, MINTER_LIST, NONE_LIST,
+        PROPOSAL_ACTION_PROPOSE_ADMIN, PROPOSAL_ACTION_SET_THRESHOLD, PROPOSAL_THRESHOLD,
+        ROLE_MEMBERS_KEY, ROLE_MEMBER_COUNTS_KEY, TOKEN_DECIMALS, TOKEN_NAME,
+        TOKEN_OWNER_ADDRESS_1, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        cep18_check_role_member_at, cep18_check_role_member_count, setup, setup_with_args,
+        setup_with_wasm, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_not_install_without_any_admin() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+            ARG_SYMBOL => TOKEN_SYMBOL,
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+            NONE_LIST => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)],
+        },
+    )
+    .build();
+    builder.exec(install_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_LAST_ADMIN),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_hand_over_admin_once_accepted() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let propose_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PROPOSE_ADMIN,
+        runtime_args! {ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR)},
+    )
+    .build();
+    builder
+        .exec(propose_admin_request)
+        .expect_success()
+        .commit();
+
+    let accept_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_ACCEPT_ADMIN,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(accept_admin_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_NOT_PENDING_ADMIN),
+        "{:?}",
+        error
+    );
+
+    let accept_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_ACCEPT_ADMIN,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(accept_admin_request).expect_success().commit();
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_PAUSE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(pause_request).expect_success().commit();
+}
+
+#[test]
+fn should_not_accept_admin_once_proposals_are_required() {
+    const ADMIN_BADGE: u8 = 0;
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+    });
+
+    let propose_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PROPOSE_ADMIN,
+        runtime_args! {ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR)},
+    )
+    .build();
+    builder
+        .exec(propose_admin_request)
+        .expect_success()
+        .commit();
+
+    let propose_threshold_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PROPOSE,
+        runtime_args! {
+            ARG_ACTION => PROPOSAL_ACTION_SET_THRESHOLD,
+            ARG_THRESHOLD => 2u64,
+        },
+    )
+    .build();
+    builder
+        .exec(propose_threshold_request)
+        .expect_success()
+        .commit();
+
+    // Requiring proposals drops the candidate proposed by a single Admin.
+    let accept_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_ACCEPT_ADMIN,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(accept_admin_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_NOT_PENDING_ADMIN),
+        "{:?}",
+        error
+    );
+
+    assert_eq!(
+        read_role_members(&builder, cep18_token, ADMIN_BADGE).len(),
+        2
+    );
+}
+
+#[test]
+fn should_not_grant_admin_through_change_security() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {
+            ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        },
+    )
+    .build();
+    builder.exec(change_security_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ADMIN_GRANT_NOT_ALLOWED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_drop_pending_admin_once_proposer_loses_admin() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+    });
+
+    let propose_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_PROPOSE_ADMIN,
+        runtime_args! {ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR)},
+    )
+    .build();
+    builder
+        .exec(propose_admin_request)
+        .expect_success()
+        .commit();
+
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {
+            NONE_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+        },
+    )
+    .build();
+    builder
+        .exec(change_security_request)
+        .expect_success()
+        .commit();
+
+    let accept_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_ACCEPT_ADMIN,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(accept_admin_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_NOT_PENDING_ADMIN),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_propose_admin_through_proposal_above_threshold() {
+    const ADMIN_BADGE: u8 = 0;
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+        PROPOSAL_THRESHOLD => 2u64,
+    });
+
+    let propose_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PROPOSE_ADMIN,
+        runtime_args! {ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR)},
+    )
+    .build();
+    builder.exec(propose_admin_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_PROPOSAL_REQUIRED),
+        "{:?}",
+        error
+    );
+
+    let propose_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PROPOSE,
+        runtime_args! {
+            ARG_ACTION => PROPOSAL_ACTION_PROPOSE_ADMIN,
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR),
+        },
+    )
+    .build();
+    builder.exec(propose_request).expect_success().commit();
+
+    // Proposing an Admin is under timelock, so the approval schedules it.
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_APPROVE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 0u64},
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_EXECUTE,
+        runtime_args! {ARG_OPERATION_ID => 0u64},
+    )
+    .build();
+    builder.exec(execute_request).expect_success().commit();
+
+    let accept_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_ACCEPT_ADMIN,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(accept_admin_request).expect_success().commit();
+
+    assert!(read_role_members(&builder, cep18_token, ADMIN_BADGE)
+        .contains(&Key::Account(*ACCOUNT_1_ADDR)));
+}
+
+#[test]
+fn should_count_admins_after_upgrade() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {ARG_NAME => TOKEN_NAME},
+    )
+    .build();
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let upgraded_token = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CEP18_TOKEN_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+    assert_ne!(upgraded_token, cep18_token);
+
+    // Both Admins are still counted, so proposals can require both of them.
+    let propose_threshold_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        upgraded_token,
+        METHOD_PROPOSE,
+        runtime_args! {
+            ARG_ACTION => PROPOSAL_ACTION_SET_THRESHOLD,
+            ARG_THRESHOLD => 3u64,
+        },
+    )
+    .build();
+    builder.exec(propose_threshold_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_PROPOSAL_THRESHOLD),
+        "{:?}",
+        error
+    );
+
+    let propose_threshold_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        upgraded_token,
+        METHOD_PROPOSE,
+        runtime_args! {
+            ARG_ACTION => PROPOSAL_ACTION_SET_THRESHOLD,
+            ARG_THRESHOLD => 2u64,
+        },
+    )
+    .build();
+    builder
+        .exec(propose_threshold_request)
+        .expect_success()
+        .commit();
+
+    let threshold: u64 = builder.get_value(upgraded_token, PROPOSAL_THRESHOLD);
+    assert_eq!(threshold, 2);
+}
+
+fn read_dictionary_item<T: CLTyped + FromBytes>(
+    builder: &InMemoryWasmTestBuilder,
+    cep18_token: ContractHash,
+    dictionary_name: &str,
+    dictionary_item_key: &str,
+) -> Option<T> {
+    let dictionary_uref = *builder
+        .get_contract(cep18_token)
+        .expect("should have contract")
+        .named_keys()
+        .get(dictionary_name)
+        .and_then(Key::as_uref)
+        .expect("should have dictionary");
+    builder
+        .query_dictionary_item(None, dictionary_uref, dictionary_item_key)
+        .ok()
+        .and_then(|value| value.as_cl_value().cloned())
+        .map(|value| value.into_t().expect("should have expected type"))
+}
+
+fn read_role_members(
+    builder: &InMemoryWasmTestBuilder,
+    cep18_token: ContractHash,
+    badge: u8,
+) -> Vec<Key> {
+    let count: u64 = read_dictionary_item(
+        builder,
+        cep18_token,
+        ROLE_MEMBER_COUNTS_KEY,
+        &badge.to_string(),
+    )
+    .unwrap_or_default();
+    let mut members: Vec<Key> = (0..count)
+        .map(|index| {
+            read_dictionary_item(
+                builder,
+                cep18_token,
+                ROLE_MEMBERS_KEY,
+                &format!("{badge}_{index}"),
+            )
+            .expect("should have member")
+        })
+        .collect();
+    members.sort();
+    members
+}
+
+#[test]
+fn should_list_role_members() {
+    const ADMIN_BADGE: u8 = 0;
+    const MINTER_BADGE: u8 = 1;
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+    });
+
+    let mut admins = vec![
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Key::Account(*ACCOUNT_1_ADDR),
+    ];
+    admins.sort();
+    assert_eq!(
+        read_role_members(&builder, cep18_token, ADMIN_BADGE),
+        admins
+    );
+    assert_eq!(
+        read_role_members(&builder, cep18_token, MINTER_BADGE),
+        vec![Key::Account(*ACCOUNT_2_ADDR)]
+    );
+
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {
+            NONE_LIST => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)],
+        },
+    )
+    .build();
+    builder
+        .exec(change_security_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        read_role_members(&builder, cep18_token, ADMIN_BADGE),
+        vec![Key::Account(*ACCOUNT_1_ADDR)]
+    );
+}
+
+#[test]
+fn should_keep_role_members_when_indexing_accounts_after_upgrade() {
+    const ADMIN_BADGE: u8 = 0;
+    const MINTER_BADGE: u8 = 1;
+
+    let (mut builder, _) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+    });
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! { ARG_NAME => TOKEN_NAME },
+    )
+    .build();
+    builder.exec(upgrade_request).expect_success().commit();
+    let upgraded_token = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CEP18_TOKEN_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+
+    // Members listed before are not listed twice.
+    let index_accounts_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        upgraded_token,
+        METHOD_INDEX_ACCOUNTS,
+        runtime_args! {
+            ARG_ACCOUNTS => vec![
+                Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                Key::Account(*ACCOUNT_1_ADDR),
+                Key::Account(*ACCOUNT_2_ADDR),
+            ],
+        },
+    )
+    .build();
+    builder
+        .exec(index_accounts_request)
+        .expect_success()
+        .commit();
+
+    let mut admins = vec![
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Key::Account(*ACCOUNT_1_ADDR),
+    ];
+    admins.sort();
+    assert_eq!(
+        read_role_members(&builder, upgraded_token, ADMIN_BADGE),
+        admins
+    );
+    assert_eq!(
+        read_role_members(&builder, upgraded_token, MINTER_BADGE),
+        vec![Key::Account(*ACCOUNT_2_ADDR)]
+    );
+}
+
+#[test]
+fn should_swap_last_role_member_into_removed_position() {
+    const MINTER_BADGE: u8 = 1;
+
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![
+            Key::Account(*ACCOUNT_1_ADDR),
+            Key::Account(*ACCOUNT_2_ADDR),
+            TOKEN_OWNER_ADDRESS_1,
+        ],
+    });
+
+    assert_eq!(
+        cep18_check_role_member_count(&mut builder, &cep18_token, MINTER_BADGE),
+        3
+    );
+    let members: Vec<Key> = (0..3)
+        .map(|index| cep18_check_role_member_at(&mut builder, &cep18_token, MINTER_BADGE, index))
+        .collect();
+
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {
+            NONE_LIST => vec![members[1]],
+        },
+    )
+    .build();
+    builder
+        .exec(change_security_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_role_member_count(&mut builder, &cep18_token, MINTER_BADGE),
+        2
+    );
+    assert_eq!(
+        cep18_check_role_member_at(&mut builder, &cep18_token, MINTER_BADGE, 0),
+        members[0]
+    );
+    assert_eq!(
+        cep18_check_role_member_at(&mut builder, &cep18_token, MINTER_BADGE, 1),
+        members[2]
+    );
+
+    let check_role_member_at_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_ROLE_MEMBER_AT_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => Key::from(cep18_token),
+            ARG_BADGE => MINTER_BADGE,
+            ARG_INDEX => 2u64,
+        },
+    )
+    .build();
+    builder.exec(check_role_member_at_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ROLE_MEMBER_INDEX_OUT_OF_RANGE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_list_role_members_of_baseline_version_once_indexed() {
+    const ADMIN_BADGE: u8 = 0;
+    const MINTER_BADGE: u8 = 1;
+
+    let (mut builder, _) = setup_with_wasm(
+        CEP18_BASELINE_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+            ARG_SYMBOL => TOKEN_SYMBOL,
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+            ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+            MINTER_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+        },
+    );
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! { ARG_NAME => TOKEN_NAME },
+    )
+    .build();
+    builder.exec(upgrade_request).expect_success().commit();
+    let upgraded_token = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CEP18_TOKEN_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+
+    // The baseline version did not list the members of each badge.
+    assert_eq!(
+        cep18_check_role_member_count(&mut builder, &upgraded_token, ADMIN_BADGE),
+        0
+    );
+
+    let index_accounts_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        upgraded_token,
+        METHOD_INDEX_ACCOUNTS,
+        runtime_args! {
+            ARG_ACCOUNTS => vec![
+                Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                Key::Account(*ACCOUNT_1_ADDR),
+                Key::Account(*ACCOUNT_2_ADDR),
+            ],
+        },
+    )
+    .build();
+    builder
+        .exec(index_accounts_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_role_member_count(&mut builder, &upgraded_token, ADMIN_BADGE),
+        2
+    );
+    let mut admins: Vec<Key> = (0..2)
+        .map(|index| cep18_check_role_member_at(&mut builder, &upgraded_token, ADMIN_BADGE, index))
+        .collect();
+    admins.sort();
+    let mut expected_admins = vec![
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Key::Account(*ACCOUNT_1_ADDR),
+    ];
+    expected_admins.sort();
+    assert_eq!(admins, expected_admins);
+
+    assert_eq!(
+        cep18_check_role_member_count(&mut builder, &upgraded_token, MINTER_BADGE),
+        1
+    );
+    assert_eq!(
+        cep18_check_role_member_at(&mut builder, &upgraded_token, MINTER_BADGE, 0),
+        Key::Account(*ACCOUNT_2_ADDR)
+    );
+}
@@ -1,18 +1,27 @@
-use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
-use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    crypto, runtime_args, ApiError, ContractHash, Key, PublicKey, RuntimeArgs, SecretKey, U256,
+};
 
 use crate::utility::{
     constants::{
-        ACCOUNT_1_ADDR, ALLOWANCE_AMOUNT_1, ALLOWANCE_AMOUNT_2, ARG_AMOUNT, ARG_OWNER,
-        ARG_RECIPIENT, ARG_SPENDER, DECREASE_ALLOWANCE, ERROR_INSUFFICIENT_ALLOWANCE,
-        INCREASE_ALLOWANCE, METHOD_APPROVE, METHOD_TRANSFER_FROM,
+        ACCOUNT_1_ADDR, ALLOWANCE_AMOUNT_1, ALLOWANCE_AMOUNT_2, ARG_AMOUNT, ARG_DEADLINE,
+        ARG_EXPIRES_AT, ARG_NONCE, ARG_OWNER, ARG_OWNER_PUBLIC_KEY, ARG_RECIPIENT, ARG_SIGNATURE,
+        ARG_SPENDER, CEP18_TOKEN_CONTRACT_PACKAGE_KEY, DECREASE_ALLOWANCE,
+        ERROR_INSUFFICIENT_ALLOWANCE, ERROR_INVALID_NONCE, ERROR_INVALID_SIGNATURE,
+        ERROR_PERMIT_EXPIRED, INCREASE_ALLOWANCE, METHOD_APPROVE, METHOD_PERMIT,
+        METHOD_TRANSFER_FROM,
     },
     installer_request_builders::{
         cep18_check_allowance_of, make_cep18_approve_request, setup, test_approve_for, TestContext,
     },
 };
 use casper_execution_engine::core::{
-    engine_state::Error as CoreError, execution::Error as ExecError,
+    engine_state::{Error as CoreError, ExecuteRequest},
+    execution::Error as ExecError,
 };
 
 #[test]
@@ -197,3 +206,214 @@ fn test_decrease_allowance() {
         (allowance_amount_1 * 2) - allowance_amount_2
     );
 }
+
+#[test]
+fn should_not_transfer_from_after_allowance_expired() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = *ACCOUNT_1_ADDR;
+    let allowance_amount = U256::from(ALLOWANCE_AMOUNT_1);
+    let transfer_from_amount = U256::one();
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => Key::Account(spender),
+            ARG_AMOUNT => allowance_amount,
+            ARG_EXPIRES_AT => 1_000u64,
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let cep18_transfer_from_args = runtime_args! {
+        ARG_OWNER => owner,
+        ARG_RECIPIENT => Key::Account(spender),
+        ARG_AMOUNT => transfer_from_amount,
+    };
+    let transfer_from_before_expiry = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        cep18_token,
+        METHOD_TRANSFER_FROM,
+        cep18_transfer_from_args.clone(),
+    )
+    .with_block_time(1_000)
+    .build();
+    builder
+        .exec(transfer_from_before_expiry)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, Key::Account(spender)),
+        allowance_amount - transfer_from_amount
+    );
+
+    let transfer_from_after_expiry = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        cep18_token,
+        METHOD_TRANSFER_FROM,
+        cep18_transfer_from_args,
+    )
+    .with_block_time(1_001)
+    .build();
+    builder.exec(transfer_from_after_expiry).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_ALLOWANCE),
+        "{:?}",
+        error
+    );
+}
+
+fn sign_permit(
+    builder: &mut InMemoryWasmTestBuilder,
+    secret_key: &SecretKey,
+    spender: Key,
+    amount: U256,
+    deadline: u64,
+    nonce: u64,
+) -> Bytes {
+    let public_key = PublicKey::from(secret_key);
+    let owner = Key::Account(public_key.to_account_hash());
+    let package_hash = *builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .get(CEP18_TOKEN_CONTRACT_PACKAGE_KEY)
+        .expect("should have package hash");
+
+    let mut preimage = b"CEP18_PERMIT".to_vec();
+    preimage.append(&mut package_hash.to_bytes().unwrap());
+    preimage.append(&mut owner.to_bytes().unwrap());
+    preimage.append(&mut spender.to_bytes().unwrap());
+    preimage.append(&mut amount.to_bytes().unwrap());
+    preimage.append(&mut deadline.to_bytes().unwrap());
+    preimage.append(&mut nonce.to_bytes().unwrap());
+    let message = crypto::blake2b(preimage);
+
+    let signature = crypto::sign(message, secret_key, &public_key);
+    // Drop the leading algorithm tag to keep the raw 64 bytes signature.
+    Bytes::from(signature.to_bytes().unwrap()[1..].to_vec())
+}
+
+fn make_permit_request(
+    cep18_token: ContractHash,
+    secret_key: &SecretKey,
+    spender: Key,
+    amount: U256,
+    deadline: u64,
+    nonce: u64,
+    signature: Bytes,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PERMIT,
+        runtime_args! {
+            ARG_OWNER_PUBLIC_KEY => PublicKey::from(secret_key),
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => amount,
+            ARG_DEADLINE => deadline,
+            ARG_NONCE => nonce,
+            ARG_SIGNATURE => signature,
+        },
+    )
+    .with_block_time(1_000)
+    .build()
+}
+
+#[test]
+fn should_approve_with_permit_and_reject_replay() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let secret_key = SecretKey::ed25519_from_bytes([7u8; 32]).unwrap();
+    let owner = Key::Account(PublicKey::from(&secret_key).to_account_hash());
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+    let amount = U256::from(ALLOWANCE_AMOUNT_1);
+
+    let signature = sign_permit(&mut builder, &secret_key, spender, amount, 2_000, 0);
+    let permit_request = make_permit_request(
+        cep18_token,
+        &secret_key,
+        spender,
+        amount,
+        2_000,
+        0,
+        signature.clone(),
+    );
+    builder.exec(permit_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, spender),
+        amount
+    );
+
+    let replay_request = make_permit_request(
+        cep18_token,
+        &secret_key,
+        spender,
+        amount,
+        2_000,
+        0,
+        signature,
+    );
+    builder.exec(replay_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_NONCE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_approve_with_expired_permit() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let secret_key = SecretKey::ed25519_from_bytes([7u8; 32]).unwrap();
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+    let amount = U256::from(ALLOWANCE_AMOUNT_1);
+
+    let signature = sign_permit(&mut builder, &secret_key, spender, amount, 500, 0);
+    let permit_request =
+        make_permit_request(cep18_token, &secret_key, spender, amount, 500, 0, signature);
+    builder.exec(permit_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_PERMIT_EXPIRED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_approve_with_permit_signed_by_another_key() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let secret_key = SecretKey::ed25519_from_bytes([7u8; 32]).unwrap();
+    let other_secret_key = SecretKey::secp256k1_from_bytes([8u8; 32]).unwrap();
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+    let amount = U256::from(ALLOWANCE_AMOUNT_1);
+
+    let signature = sign_permit(&mut builder, &other_secret_key, spender, amount, 2_000, 0);
+    let permit_request = make_permit_request(
+        cep18_token,
+        &secret_key,
+        spender,
+        amount,
+        2_000,
+        0,
+        signature,
+    );
+    builder.exec(permit_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_SIGNATURE),
+        "{:?}",
+        error
+    );
+}
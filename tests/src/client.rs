@@ -0,0 +1,181 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{ApiError, Key, U256};
+use cep18_client::{
+    calls,
+    constants::{ALLOWANCES, BALANCES, PROPOSAL_THRESHOLD, TIMELOCK_DELAY, TRANSFER_FILTER_MODE},
+    dictionary_keys::{allowance_key, balance_key},
+    InstallArgs, TransferFilterMode,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ERROR_INSUFFICIENT_ALLOWANCE, TOKEN_DECIMALS, TOKEN_NAME,
+        TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        cep18_check_allowance_of, cep18_check_balance_of, setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+fn setup_with_client() -> (InMemoryWasmTestBuilder, TestContext) {
+    let install_args = InstallArgs::new(
+        TOKEN_NAME,
+        TOKEN_SYMBOL,
+        TOKEN_DECIMALS,
+        U256::from(TOKEN_TOTAL_SUPPLY),
+    );
+    setup_with_args(install_args.to_runtime_args().unwrap())
+}
+
+#[test]
+fn should_transfer_with_client_call() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_client();
+    let transfer_amount = U256::from(1_000);
+
+    let transfer = calls::transfer(Key::Account(*ACCOUNT_1_ADDR), transfer_amount);
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        transfer.entry_point,
+        transfer.args,
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        transfer_amount
+    );
+}
+
+#[test]
+fn should_query_balances_and_allowances_with_client_keys() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_client();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_2_ADDR);
+    let allowance_amount = U256::from(500);
+
+    let approve = calls::approve(spender, allowance_amount, None).unwrap();
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        approve.entry_point,
+        approve.args,
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let named_keys = builder
+        .get_contract(cep18_token)
+        .expect("should have contract")
+        .named_keys()
+        .clone();
+    let balances_uref = *named_keys
+        .get(BALANCES)
+        .and_then(Key::as_uref)
+        .expect("should have balances");
+    let allowances_uref = *named_keys
+        .get(ALLOWANCES)
+        .and_then(Key::as_uref)
+        .expect("should have allowances");
+
+    let balance: U256 = builder
+        .query_dictionary_item(None, balances_uref, &balance_key(&owner))
+        .expect("should have balance")
+        .as_cl_value()
+        .cloned()
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be U256");
+    assert_eq!(
+        balance,
+        cep18_check_balance_of(&mut builder, &cep18_token, owner)
+    );
+
+    let allowance: U256 = builder
+        .query_dictionary_item(None, allowances_uref, &allowance_key(&owner, &spender))
+        .expect("should have allowance")
+        .as_cl_value()
+        .cloned()
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be U256");
+    assert_eq!(allowance, allowance_amount);
+    assert_eq!(
+        allowance,
+        cep18_check_allowance_of(&mut builder, owner, spender)
+    );
+}
+
+#[test]
+fn should_install_with_client_governance_args() {
+    let mut install_args = InstallArgs::new(
+        TOKEN_NAME,
+        TOKEN_SYMBOL,
+        TOKEN_DECIMALS,
+        U256::from(TOKEN_TOTAL_SUPPLY),
+    );
+    install_args.transfer_filter_mode = Some(TransferFilterMode::Allowlist);
+    install_args.proposal_threshold = Some(2);
+    install_args.timelock_delay = Some(1_000);
+    install_args.security_lists.admin_list = Some(vec![Key::Account(*ACCOUNT_1_ADDR)]);
+    let (builder, TestContext { cep18_token, .. }) =
+        setup_with_args(install_args.to_runtime_args().unwrap());
+
+    let transfer_filter_mode: u8 = builder.get_value(cep18_token, TRANSFER_FILTER_MODE);
+    assert_eq!(transfer_filter_mode, TransferFilterMode::Allowlist as u8);
+    let proposal_threshold: u64 = builder.get_value(cep18_token, PROPOSAL_THRESHOLD);
+    assert_eq!(proposal_threshold, 2);
+    let timelock_delay: u64 = builder.get_value(cep18_token, TIMELOCK_DELAY);
+    assert_eq!(timelock_delay, 1_000);
+}
+
+#[test]
+fn should_increase_allowance_with_client_expiry() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_client();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = *ACCOUNT_1_ADDR;
+    let allowance_amount = U256::from(500);
+
+    let increase_allowance =
+        calls::increase_allowance(Key::Account(spender), allowance_amount, Some(1_000)).unwrap();
+    let increase_allowance_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        increase_allowance.entry_point,
+        increase_allowance.args,
+    )
+    .build();
+    builder
+        .exec(increase_allowance_request)
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, Key::Account(spender)),
+        allowance_amount
+    );
+
+    let transfer_from = calls::transfer_from(owner, Key::Account(spender), U256::one());
+    let transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        cep18_token,
+        transfer_from.entry_point,
+        transfer_from.args,
+    )
+    .with_block_time(1_001)
+    .build();
+    builder.exec(transfer_from_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_ALLOWANCE),
+        "{:?}",
+        error
+    );
+}
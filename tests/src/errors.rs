@@ -0,0 +1,61 @@
+use casper_engine_test_support::ExecuteRequestBuilder;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    runtime_args, ApiError, Key, RuntimeArgs, U256,
+};
+use cep18_types::{Cep18Error, SecurityBadge};
+use core::convert::TryFrom;
+
+use crate::utility::{
+    constants::{ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_RECIPIENT, METHOD_TRANSFER},
+    installer_request_builders::{setup, TestContext},
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_decode_revert_into_cep18_error() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    let user_error = match error {
+        CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) => user_error,
+        other => panic!("unexpected error {:?}", other),
+    };
+    let cep18_error = Cep18Error::try_from(user_error).expect("should be a cep18 error");
+    assert_eq!(cep18_error, Cep18Error::InsufficientBalance);
+    assert_eq!(
+        cep18_error.to_string(),
+        "Spender does not have enough balance (60001)"
+    );
+    assert_eq!(Cep18Error::try_from(1u16), Err(1u16));
+}
+
+#[test]
+fn should_not_decode_unknown_security_badge() {
+    let bytes = SecurityBadge::KycAdmin.to_bytes().unwrap();
+    assert_eq!(
+        SecurityBadge::from_bytes(&bytes),
+        Ok((SecurityBadge::KycAdmin, &[][..]))
+    );
+
+    assert_eq!(
+        SecurityBadge::from_bytes(&[6u8]),
+        Err(bytesrepr::Error::Formatting)
+    );
+}
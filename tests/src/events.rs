@@ -0,0 +1,324 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{
+    account::AccountHash, runtime_args, CLValue, ContractHash, Key, RuntimeArgs, U256,
+};
+use cep18_types::{
+    events::{BurnFrom, Transfer},
+    read_events, Cep18Event, EventSource, Ledger,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_AMOUNTS, ARG_DECIMALS, ARG_NAME, ARG_OWNER,
+        ARG_RECIPIENT, ARG_RECIPIENTS, ARG_SPENDER, ARG_SYMBOL, ARG_TOTAL_SUPPLY, BURNER_LIST,
+        DECREASE_ALLOWANCE, ENABLE_MINT_BURN, EVENTS_KEY, EVENTS_LENGTH_KEY, EVENTS_MODE,
+        EVENTS_SCHEMA_KEY, INCREASE_ALLOWANCE, METHOD_APPROVE, METHOD_BATCH_TRANSFER, METHOD_BURN,
+        METHOD_BURN_FROM, METHOD_MINT, METHOD_TRANSFER, METHOD_TRANSFER_FROM, TOKEN_DECIMALS,
+        TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY, TOTAL_SUPPLY_KEY,
+    },
+    installer_request_builders::{
+        cep18_check_allowance_of, cep18_check_balance_of, setup_with_args, TestContext,
+    },
+};
+
+/// Events of the installed token, read from the in-memory global state.
+struct ContractEvents<'a> {
+    builder: &'a InMemoryWasmTestBuilder,
+    cep18_token: ContractHash,
+}
+
+impl ContractEvents<'_> {
+    fn named_key(&self, name: &str) -> Result<Key, String> {
+        self.builder
+            .get_contract(self.cep18_token)
+            .ok_or_else(|| "missing contract".to_string())?
+            .named_keys()
+            .get(name)
+            .copied()
+            .ok_or_else(|| format!("missing named key {}", name))
+    }
+
+    fn named_value(&self, name: &str) -> Result<CLValue, String> {
+        self.builder
+            .query(None, self.named_key(name)?, &[])?
+            .as_cl_value()
+            .cloned()
+            .ok_or_else(|| format!("{} is not a CLValue", name))
+    }
+}
+
+impl EventSource for ContractEvents<'_> {
+    type Error = String;
+
+    fn events_schema(&self) -> Result<CLValue, String> {
+        self.named_value(EVENTS_SCHEMA_KEY)
+    }
+
+    fn events_length(&self) -> Result<CLValue, String> {
+        self.named_value(EVENTS_LENGTH_KEY)
+    }
+
+    fn event(&self, index: u32) -> Result<CLValue, String> {
+        let events_uref = *self
+            .named_key(EVENTS_KEY)?
+            .as_uref()
+            .ok_or_else(|| "events are not a dictionary".to_string())?;
+        self.builder
+            .query_dictionary_item(None, events_uref, &index.to_string())?
+            .as_cl_value()
+            .cloned()
+            .ok_or_else(|| format!("event {} is not a CLValue", index))
+    }
+}
+
+fn call(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_token: ContractHash,
+    sender: AccountHash,
+    entry_point: &str,
+    args: RuntimeArgs,
+) {
+    let request =
+        ExecuteRequestBuilder::contract_call_by_hash(sender, cep18_token, entry_point, args)
+            .build();
+    builder.exec(request).expect_success().commit();
+}
+
+#[test]
+fn should_rebuild_ledger_from_events() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        EVENTS_MODE => 1u8,
+        ENABLE_MINT_BURN => true,
+        BURNER_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2 = Key::Account(*ACCOUNT_2_ADDR);
+
+    call(
+        &mut builder,
+        cep18_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_TRANSFER,
+        runtime_args! {ARG_RECIPIENT => account_1, ARG_AMOUNT => U256::from(1_000)},
+    );
+    call(
+        &mut builder,
+        cep18_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_APPROVE,
+        runtime_args! {ARG_SPENDER => account_2, ARG_AMOUNT => U256::from(300)},
+    );
+    call(
+        &mut builder,
+        cep18_token,
+        *ACCOUNT_1_ADDR,
+        INCREASE_ALLOWANCE,
+        runtime_args! {ARG_SPENDER => account_2, ARG_AMOUNT => U256::from(200)},
+    );
+    call(
+        &mut builder,
+        cep18_token,
+        *ACCOUNT_1_ADDR,
+        DECREASE_ALLOWANCE,
+        runtime_args! {ARG_SPENDER => account_2, ARG_AMOUNT => U256::from(100)},
+    );
+    call(
+        &mut builder,
+        cep18_token,
+        *ACCOUNT_2_ADDR,
+        METHOD_TRANSFER_FROM,
+        runtime_args! {
+            ARG_OWNER => account_1,
+            ARG_RECIPIENT => owner,
+            ARG_AMOUNT => U256::from(150),
+        },
+    );
+    call(
+        &mut builder,
+        cep18_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_MINT,
+        runtime_args! {ARG_OWNER => account_1, ARG_AMOUNT => U256::from(500)},
+    );
+    call(
+        &mut builder,
+        cep18_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_BURN,
+        runtime_args! {ARG_OWNER => owner, ARG_AMOUNT => U256::from(200)},
+    );
+    // A burner burning tokens of someone else consumes their allowance, recorded as `BurnFrom`.
+    call(
+        &mut builder,
+        cep18_token,
+        *ACCOUNT_2_ADDR,
+        METHOD_BURN,
+        runtime_args! {ARG_OWNER => account_1, ARG_AMOUNT => U256::from(50)},
+    );
+    call(
+        &mut builder,
+        cep18_token,
+        *ACCOUNT_2_ADDR,
+        METHOD_BURN_FROM,
+        runtime_args! {ARG_OWNER => account_1, ARG_AMOUNT => U256::from(20)},
+    );
+
+    let events = read_events(
+        &ContractEvents {
+            builder: &builder,
+            cep18_token,
+        },
+        0,
+    )
+    .expect("should read events");
+    assert_eq!(
+        events.first(),
+        Some(&Cep18Event::Transfer(Transfer {
+            sender: owner,
+            recipient: account_1,
+            amount: U256::from(1_000),
+        }))
+    );
+    assert!(events.contains(&Cep18Event::BurnFrom(BurnFrom {
+        spender: account_2,
+        owner: account_1,
+        amount: U256::from(50),
+    })));
+    assert_eq!(
+        events.last(),
+        Some(&Cep18Event::BurnFrom(BurnFrom {
+            spender: account_2,
+            owner: account_1,
+            amount: U256::from(20),
+        }))
+    );
+
+    let mut ledger = Ledger::new(owner, U256::from(TOKEN_TOTAL_SUPPLY));
+    ledger.replay(&events).expect("should replay events");
+
+    for account in [owner, account_1, account_2] {
+        assert_eq!(
+            ledger.balance_of(account),
+            cep18_check_balance_of(&mut builder, &cep18_token, account)
+        );
+    }
+    assert_eq!(ledger.allowance(account_1, account_2), U256::from(180));
+    assert_eq!(
+        ledger.allowance(account_1, account_2),
+        cep18_check_allowance_of(&mut builder, account_1, account_2)
+    );
+    let total_supply: U256 = builder.get_value(cep18_token, TOTAL_SUPPLY_KEY);
+    assert_eq!(ledger.total_supply(), total_supply);
+}
+
+#[test]
+fn should_read_events_from_index() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        EVENTS_MODE => 1u8,
+    });
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2 = Key::Account(*ACCOUNT_2_ADDR);
+
+    call(
+        &mut builder,
+        cep18_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_TRANSFER,
+        runtime_args! {ARG_RECIPIENT => account_1, ARG_AMOUNT => U256::from(1_000)},
+    );
+    let processed = read_events(
+        &ContractEvents {
+            builder: &builder,
+            cep18_token,
+        },
+        0,
+    )
+    .expect("should read events")
+    .len() as u32;
+
+    call(
+        &mut builder,
+        cep18_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_TRANSFER,
+        runtime_args! {ARG_RECIPIENT => account_2, ARG_AMOUNT => U256::from(400)},
+    );
+    let new_events = read_events(
+        &ContractEvents {
+            builder: &builder,
+            cep18_token,
+        },
+        processed,
+    )
+    .expect("should read events");
+    assert_eq!(
+        new_events,
+        vec![Cep18Event::Transfer(Transfer {
+            sender: account_1,
+            recipient: account_2,
+            amount: U256::from(400),
+        })]
+    );
+}
+
+#[test]
+fn should_not_record_zero_amount_batch_transfer_legs() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        EVENTS_MODE => 1u8,
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2 = Key::Account(*ACCOUNT_2_ADDR);
+
+    let processed = read_events(
+        &ContractEvents {
+            builder: &builder,
+            cep18_token,
+        },
+        0,
+    )
+    .expect("should read events")
+    .len() as u32;
+
+    call(
+        &mut builder,
+        cep18_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_BATCH_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENTS => vec![account_1, account_2],
+            ARG_AMOUNTS => vec![U256::from(400), U256::zero()],
+        },
+    );
+    let new_events = read_events(
+        &ContractEvents {
+            builder: &builder,
+            cep18_token,
+        },
+        processed,
+    )
+    .expect("should read events");
+    assert_eq!(
+        new_events,
+        vec![Cep18Event::Transfer(Transfer {
+            sender: owner,
+            recipient: account_1,
+            amount: U256::from(400),
+        })]
+    );
+}
@@ -0,0 +1,78 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_DECIMALS, ARG_NAME, ARG_RECIPIENT, ARG_SYMBOL,
+        ARG_TOTAL_SUPPLY, CEP18_EXAMPLE_CONTRACT_WASM, ENABLE_MINT_BURN, ERROR_INSUFFICIENT_RIGHTS,
+        METHOD_AIRDROP, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+        TRANSFER_AMOUNT_1,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, cep18_check_total_supply, make_cep18_transfer_request,
+        setup_with_wasm, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_call_entry_point_added_by_example_token() {
+    let airdrop_amount = U256::from(100);
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_wasm(
+        CEP18_EXAMPLE_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+            ARG_SYMBOL => TOKEN_SYMBOL,
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+            ENABLE_MINT_BURN => true,
+        },
+    );
+
+    let airdrop_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_AIRDROP,
+        runtime_args! {ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR)},
+    )
+    .build();
+    builder.exec(airdrop_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        airdrop_amount
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY) + airdrop_amount
+    );
+
+    // The standard entry points are still exported.
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_2_ADDR),
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    let airdrop_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_AIRDROP,
+        runtime_args! {ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR)},
+    )
+    .build();
+    builder.exec(airdrop_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+}
@@ -0,0 +1,242 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ADDRESS, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_OWNER,
+        ARG_SYMBOL, ARG_TOTAL_SUPPLY, BURNER_LIST, ENABLE_MINT_BURN, ERROR_ACCOUNT_FROZEN,
+        METHOD_BURN, METHOD_FREEZE_ACCOUNT, METHOD_UNFREEZE_ACCOUNT, TOKEN_DECIMALS, TOKEN_NAME,
+        TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1, TRANSFER_AMOUNT_2,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, make_cep18_approve_request, make_cep18_transfer_request, setup,
+        setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_not_transfer_to_or_from_frozen_account() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        transfer_amount,
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FREEZE_ACCOUNT,
+        runtime_args! { ARG_ADDRESS => Key::Account(*ACCOUNT_1_ADDR) },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
+
+    let transfer_to_frozen_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        transfer_amount,
+    );
+    builder.exec(transfer_to_frozen_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_FROZEN),
+        "{:?}",
+        error
+    );
+
+    let transfer_from_frozen_request = make_cep18_transfer_request(
+        Key::Account(*ACCOUNT_1_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_2_ADDR),
+        U256::from(TRANSFER_AMOUNT_2),
+    );
+    builder.exec(transfer_from_frozen_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_FROZEN),
+        "{:?}",
+        error
+    );
+
+    let approve_frozen_request = make_cep18_approve_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        transfer_amount,
+    );
+    builder.exec(approve_frozen_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_FROZEN),
+        "{:?}",
+        error
+    );
+
+    let unfreeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_UNFREEZE_ACCOUNT,
+        runtime_args! { ARG_ADDRESS => Key::Account(*ACCOUNT_1_ADDR) },
+    )
+    .build();
+    builder.exec(unfreeze_request).expect_success().commit();
+
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*ACCOUNT_1_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_2_ADDR),
+        U256::from(TRANSFER_AMOUNT_2),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::from(TRANSFER_AMOUNT_2)
+    );
+}
+
+#[test]
+fn should_only_allow_admin_to_freeze() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_FREEZE_ACCOUNT,
+        runtime_args! { ARG_ADDRESS => Key::Account(*DEFAULT_ACCOUNT_ADDR) },
+    )
+    .build();
+    builder.exec(freeze_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == 60010),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_let_burner_burn_tokens_of_frozen_account() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        BURNER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+    let holder = Key::Account(*ACCOUNT_2_ADDR);
+    let burn_amount = U256::from(TRANSFER_AMOUNT_2);
+
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        holder,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+    let approve_request = make_cep18_approve_request(
+        holder,
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        burn_amount,
+    );
+    builder.exec(approve_request).expect_success().commit();
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FREEZE_ACCOUNT,
+        runtime_args! { ARG_ADDRESS => holder },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => holder,
+            ARG_AMOUNT => burn_amount,
+        },
+    )
+    .build();
+    builder.exec(burn_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_FROZEN),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, holder),
+        U256::from(TRANSFER_AMOUNT_1)
+    );
+}
+
+#[test]
+fn should_not_change_allowances_involving_frozen_account() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let frozen = Key::Account(*ACCOUNT_1_ADDR);
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_FREEZE_ACCOUNT,
+        runtime_args! { ARG_ADDRESS => frozen },
+    )
+    .build();
+    builder.exec(freeze_request).expect_success().commit();
+
+    // A frozen spender, then a frozen owner.
+    let calls = [
+        (*DEFAULT_ACCOUNT_ADDR, INCREASE_ALLOWANCE, frozen),
+        (*DEFAULT_ACCOUNT_ADDR, DECREASE_ALLOWANCE, frozen),
+        (
+            *ACCOUNT_1_ADDR,
+            INCREASE_ALLOWANCE,
+            Key::Account(*ACCOUNT_2_ADDR),
+        ),
+        (
+            *ACCOUNT_1_ADDR,
+            DECREASE_ALLOWANCE,
+            Key::Account(*ACCOUNT_2_ADDR),
+        ),
+    ];
+    for (owner, method, spender) in calls {
+        let request = ExecuteRequestBuilder::contract_call_by_hash(
+            owner,
+            cep18_token,
+            method,
+            runtime_args! {
+                ARG_SPENDER => spender,
+                ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_2),
+            },
+        )
+        .build();
+        builder.exec(request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ACCOUNT_FROZEN),
+            "{:?}",
+            error
+        );
+    }
+}
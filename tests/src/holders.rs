@@ -0,0 +1,186 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, ApiError, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ACCOUNTS, ARG_INDEX, ARG_NAME, ARG_TOKEN_CONTRACT,
+        CEP18_CONTRACT_WASM, CEP18_TOKEN_CONTRACT_KEY, CHECK_HOLDER_AT_ENTRYPOINT,
+        ERROR_HOLDER_INDEX_OUT_OF_RANGE, ERROR_INSUFFICIENT_RIGHTS, HOLDERS_KEY, HOLDER_COUNT_KEY,
+        METHOD_INDEX_ACCOUNTS, TOKEN_NAME, TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1,
+    },
+    installer_request_builders::{
+        cep18_check_holder_at, cep18_check_holder_count, make_cep18_transfer_request, setup,
+        TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+fn read_holder_at(builder: &InMemoryWasmTestBuilder, cep18_token: ContractHash, index: u64) -> Key {
+    let holders_uref = *builder
+        .get_contract(cep18_token)
+        .expect("should have contract")
+        .named_keys()
+        .get(HOLDERS_KEY)
+        .and_then(Key::as_uref)
+        .expect("should have holders dictionary");
+    builder
+        .query_dictionary_item(None, holders_uref, &index.to_string())
+        .expect("should have holder")
+        .as_cl_value()
+        .cloned()
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be a Key")
+}
+
+#[test]
+fn should_index_holders_as_balances_change() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2 = Key::Account(*ACCOUNT_2_ADDR);
+
+    let holder_count: u64 = builder.get_value(cep18_token, HOLDER_COUNT_KEY);
+    assert_eq!(holder_count, 1);
+    assert_eq!(read_holder_at(&builder, cep18_token, 0), owner);
+
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+    let transfer_request =
+        make_cep18_transfer_request(owner, &cep18_token, account_1, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    let holder_count: u64 = builder.get_value(cep18_token, HOLDER_COUNT_KEY);
+    assert_eq!(holder_count, 2);
+    assert_eq!(read_holder_at(&builder, cep18_token, 1), account_1);
+
+    // Emptying the first holder moves the last one into its position.
+    let remaining_amount = U256::from(TOKEN_TOTAL_SUPPLY) - transfer_amount;
+    let transfer_request =
+        make_cep18_transfer_request(owner, &cep18_token, account_2, remaining_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    let holder_count: u64 = builder.get_value(cep18_token, HOLDER_COUNT_KEY);
+    assert_eq!(holder_count, 2);
+    assert_eq!(read_holder_at(&builder, cep18_token, 0), account_1);
+    assert_eq!(read_holder_at(&builder, cep18_token, 1), account_2);
+}
+
+#[test]
+fn should_index_accounts_after_upgrade() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2 = Key::Account(*ACCOUNT_2_ADDR);
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_token,
+        account_1,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! { ARG_NAME => TOKEN_NAME },
+    )
+    .build();
+    builder.exec(upgrade_request).expect_success().commit();
+    let upgraded_token = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CEP18_TOKEN_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+
+    let index_accounts_args = runtime_args! {ARG_ACCOUNTS => vec![owner, account_1, account_2]};
+    let index_accounts_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        upgraded_token,
+        METHOD_INDEX_ACCOUNTS,
+        index_accounts_args.clone(),
+    )
+    .build();
+    builder.exec(index_accounts_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_RIGHTS),
+        "{:?}",
+        error
+    );
+
+    // Holders already indexed keep their position and accounts without tokens are skipped.
+    let index_accounts_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        upgraded_token,
+        METHOD_INDEX_ACCOUNTS,
+        index_accounts_args,
+    )
+    .build();
+    builder
+        .exec(index_accounts_request)
+        .expect_success()
+        .commit();
+
+    let holder_count: u64 = builder.get_value(upgraded_token, HOLDER_COUNT_KEY);
+    assert_eq!(holder_count, 2);
+    assert_eq!(read_holder_at(&builder, upgraded_token, 0), owner);
+    assert_eq!(read_holder_at(&builder, upgraded_token, 1), account_1);
+}
+
+#[test]
+fn should_read_holders_through_entry_points() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_token,
+        account_1,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(cep18_check_holder_count(&mut builder, &cep18_token), 2);
+    assert_eq!(cep18_check_holder_at(&mut builder, &cep18_token, 0), owner);
+    assert_eq!(
+        cep18_check_holder_at(&mut builder, &cep18_token, 1),
+        account_1
+    );
+
+    let check_holder_at_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_HOLDER_AT_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => Key::from(cep18_token),
+            ARG_INDEX => 2u64,
+        },
+    )
+    .build();
+    builder.exec(check_holder_at_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_HOLDER_INDEX_OUT_OF_RANGE),
+        "{:?}",
+        error
+    );
+}
@@ -1,16 +1,28 @@
-use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
-use casper_types::{Key, U256};
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, ContractHash, Key, RuntimeArgs, U256};
 
 use crate::utility::{
     constants::{
-        ALLOWANCES_KEY, BALANCES_KEY, DECIMALS_KEY, NAME_KEY, SYMBOL_KEY, TOKEN_DECIMALS,
-        TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY, TOTAL_SUPPLY_KEY,
+        ACCOUNT_1_ADDR, ADMIN_LIST, ALLOWANCES_KEY, ARG_ACCOUNT, ARG_ACCOUNTS, ARG_ACTION,
+        ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_OWNER, ARG_SYMBOL, ARG_THRESHOLD, ARG_TOTAL_SUPPLY,
+        BALANCES_KEY, CEP18_BASELINE_CONTRACT_WASM, CEP18_CONTRACT_WASM, CEP18_TOKEN_CONTRACT_KEY,
+        CEP18_TOKEN_CONTRACT_VERSION_KEY, DECIMALS_KEY, ENABLE_MINT_BURN,
+        ERROR_INVALID_PROPOSAL_THRESHOLD, ERROR_MINTER_ALLOWANCE_EXCEEDED, HOLDER_COUNT_KEY,
+        METHOD_INDEX_ACCOUNTS, METHOD_MINT, METHOD_PROPOSE, METHOD_SET_MINTER_ALLOWANCE,
+        MINTER_LIST, NAME_KEY, PROPOSAL_ACTION_SET_THRESHOLD, PROPOSAL_THRESHOLD, SYMBOL_KEY,
+        TOKEN_DECIMALS, TOKEN_NAME, TOKEN_OWNER_ADDRESS_1, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+        TOTAL_SUPPLY_KEY, TRANSFER_AMOUNT_1,
     },
     installer_request_builders::{
-        cep18_check_balance_of, invert_cep18_address, setup, TestContext,
+        cep18_check_balance_of, invert_cep18_address, make_cep18_transfer_request, setup,
+        setup_with_wasm, TestContext,
     },
 };
 
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
 #[test]
 fn should_have_queryable_properties() {
     let (mut builder, TestContext { cep18_token, .. }) = setup();
@@ -56,3 +68,246 @@ fn should_not_store_balances_or_allowances_under_account_after_install() {
     assert!(!named_keys.contains_key(BALANCES_KEY), "{:?}", named_keys);
     assert!(!named_keys.contains_key(ALLOWANCES_KEY), "{:?}", named_keys);
 }
+
+#[test]
+fn should_upgrade_contract_and_keep_state() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        transfer_amount,
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! { ARG_NAME => TOKEN_NAME },
+    )
+    .build();
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let upgraded_token = account
+        .named_keys()
+        .get(CEP18_TOKEN_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+    assert_ne!(upgraded_token, cep18_token);
+
+    let contract_version: u32 = builder
+        .query(
+            None,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            &[CEP18_TOKEN_CONTRACT_VERSION_KEY.to_string()],
+        )
+        .expect("should have contract version")
+        .as_cl_value()
+        .cloned()
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be u32");
+    assert_eq!(contract_version, 2);
+
+    let total_supply: U256 = builder.get_value(upgraded_token, TOTAL_SUPPLY_KEY);
+    assert_eq!(total_supply, U256::from(TOKEN_TOTAL_SUPPLY));
+
+    let account_1_balance =
+        cep18_check_balance_of(&mut builder, &upgraded_token, Key::Account(*ACCOUNT_1_ADDR));
+    assert_eq!(account_1_balance, transfer_amount);
+
+    let owner_balance = cep18_check_balance_of(
+        &mut builder,
+        &upgraded_token,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+    );
+    assert_eq!(
+        owner_balance,
+        U256::from(TOKEN_TOTAL_SUPPLY) - transfer_amount
+    );
+}
+
+#[test]
+fn should_upgrade_token_installed_by_baseline_version() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_wasm(
+        CEP18_BASELINE_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+            ARG_SYMBOL => TOKEN_SYMBOL,
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+            ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        },
+    );
+
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        transfer_amount,
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! { ARG_NAME => TOKEN_NAME },
+    )
+    .build();
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let upgraded_token = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CEP18_TOKEN_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+    assert_ne!(upgraded_token, cep18_token);
+
+    let account_1_balance =
+        cep18_check_balance_of(&mut builder, &upgraded_token, Key::Account(*ACCOUNT_1_ADDR));
+    assert_eq!(account_1_balance, transfer_amount);
+
+    // Admins of the baseline version are not counted until they are indexed.
+    let propose_threshold_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        upgraded_token,
+        METHOD_PROPOSE,
+        runtime_args! {
+            ARG_ACTION => PROPOSAL_ACTION_SET_THRESHOLD,
+            ARG_THRESHOLD => 2u64,
+        },
+    )
+    .build();
+    builder.exec(propose_threshold_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_PROPOSAL_THRESHOLD),
+        "{:?}",
+        error
+    );
+
+    let index_accounts_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        upgraded_token,
+        METHOD_INDEX_ACCOUNTS,
+        runtime_args! {
+            ARG_ACCOUNTS => vec![
+                Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                Key::Account(*ACCOUNT_1_ADDR),
+            ],
+        },
+    )
+    .build();
+    builder
+        .exec(index_accounts_request)
+        .expect_success()
+        .commit();
+
+    let holder_count: u64 = builder.get_value(upgraded_token, HOLDER_COUNT_KEY);
+    assert_eq!(holder_count, 2);
+
+    let propose_threshold_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        upgraded_token,
+        METHOD_PROPOSE,
+        runtime_args! {
+            ARG_ACTION => PROPOSAL_ACTION_SET_THRESHOLD,
+            ARG_THRESHOLD => 2u64,
+        },
+    )
+    .build();
+    builder
+        .exec(propose_threshold_request)
+        .expect_success()
+        .commit();
+
+    let threshold: u64 = builder.get_value(upgraded_token, PROPOSAL_THRESHOLD);
+    assert_eq!(threshold, 2);
+}
+
+#[test]
+fn should_not_let_baseline_minters_mint_until_given_allowance() {
+    let mint_amount = U256::one();
+
+    let (mut builder, _) = setup_with_wasm(
+        CEP18_BASELINE_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+            ARG_SYMBOL => TOKEN_SYMBOL,
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+            ENABLE_MINT_BURN => true,
+            MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        },
+    );
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! { ARG_NAME => TOKEN_NAME },
+    )
+    .build();
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let upgraded_token = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CEP18_TOKEN_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+
+    let make_mint_request = || {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_1_ADDR,
+            upgraded_token,
+            METHOD_MINT,
+            runtime_args! {
+                ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+                ARG_AMOUNT => mint_amount,
+            },
+        )
+        .build()
+    };
+    builder.exec(make_mint_request()).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MINTER_ALLOWANCE_EXCEEDED),
+        "{:?}",
+        error
+    );
+
+    let set_minter_allowance_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        upgraded_token,
+        METHOD_SET_MINTER_ALLOWANCE,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => mint_amount,
+        },
+    )
+    .build();
+    builder
+        .exec(set_minter_allowance_request)
+        .expect_success()
+        .commit();
+
+    builder.exec(make_mint_request()).expect_success().commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &upgraded_token, TOKEN_OWNER_ADDRESS_1),
+        mint_amount
+    );
+}
@@ -1,10 +1,38 @@
 #[cfg(test)]
+mod admin;
+#[cfg(test)]
 mod allowance;
 #[cfg(test)]
+mod client;
+#[cfg(test)]
+mod errors;
+#[cfg(test)]
+mod events;
+#[cfg(test)]
+mod example;
+#[cfg(test)]
+mod freeze;
+#[cfg(test)]
+mod holders;
+#[cfg(test)]
 mod install;
 #[cfg(test)]
 mod mint_and_burn;
 #[cfg(test)]
+mod pause;
+#[cfg(test)]
+mod proposals;
+#[cfg(test)]
+mod snapshot;
+#[cfg(test)]
+mod timelock;
+#[cfg(test)]
 mod transfer;
 #[cfg(test)]
+mod transfer_and_call;
+#[cfg(test)]
 mod utility;
+#[cfg(test)]
+mod votes;
+#[cfg(test)]
+mod whitelist;
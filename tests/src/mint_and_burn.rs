@@ -1,16 +1,24 @@
-use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
-use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, ApiError, ContractHash, Key, RuntimeArgs, U256};
 
 use crate::utility::{
     constants::{
-        ACCOUNT_1_ADDR, ADMIN_LIST, AMOUNT, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_OWNER,
-        ARG_SYMBOL, ARG_TOTAL_SUPPLY, CHANGE_SECURITY, ENABLE_MINT_BURN,
-        ERROR_INSUFFICIENT_BALANCE, ERROR_OVERFLOW, METHOD_BURN, METHOD_MINT, MINTER_LIST,
-        NONE_LIST, OWNER, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_OWNER_ADDRESS_1, TOKEN_OWNER_ADDRESS_2,
-        TOKEN_OWNER_AMOUNT_1, TOKEN_OWNER_AMOUNT_2, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ADMIN_LIST, ALLOWANCE_AMOUNT_1, ALLOWANCE_AMOUNT_2, AMOUNT,
+        ARG_ACCOUNT, ARG_AMOUNT, ARG_AMOUNTS, ARG_DECIMALS, ARG_NAME, ARG_OWNER, ARG_RECIPIENTS,
+        ARG_SYMBOL, ARG_TOTAL_SUPPLY, BURNER_LIST, CHANGE_SECURITY, ENABLE_MINT_BURN,
+        ERROR_EXCEEDS_MAX_SUPPLY, ERROR_INSUFFICIENT_ALLOWANCE, ERROR_INSUFFICIENT_BALANCE,
+        ERROR_MINTER_ALLOWANCE_EXCEEDED, ERROR_OVERFLOW, KYC_ADMIN_LIST, MAX_SUPPLY,
+        METHOD_BATCH_MINT, METHOD_BURN, METHOD_BURN_FROM, METHOD_MINT, METHOD_SET_MINTER_ALLOWANCE,
+        MINTER_LIST, MINT_AND_BURN_LIST, NONE_LIST, OWNER, PROPOSAL_THRESHOLD, TOKEN_DECIMALS,
+        TOKEN_NAME, TOKEN_OWNER_ADDRESS_1, TOKEN_OWNER_ADDRESS_2, TOKEN_OWNER_AMOUNT_1,
+        TOKEN_OWNER_AMOUNT_2, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
     },
     installer_request_builders::{
-        cep18_check_balance_of, cep18_check_total_supply, setup_with_args, TestContext,
+        cep18_check_allowance_of, cep18_check_badge, cep18_check_balance_of, cep18_check_is_admin,
+        cep18_check_is_minter, cep18_check_total_supply, make_cep18_approve_request,
+        setup_with_args, TestContext,
     },
 };
 
@@ -18,6 +26,28 @@ use casper_execution_engine::core::{
     engine_state::Error as CoreError, execution::Error as ExecError,
 };
 
+fn set_minter_allowance(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_token: ContractHash,
+    minter: Key,
+    allowance: U256,
+) {
+    let set_minter_allowance_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_MINTER_ALLOWANCE,
+        runtime_args! {
+            ARG_ACCOUNT => minter,
+            ARG_AMOUNT => allowance,
+        },
+    )
+    .build();
+    builder
+        .exec(set_minter_allowance_request)
+        .expect_success()
+        .commit();
+}
+
 #[test]
 fn test_mint_and_burn_tokens() {
     let mint_amount = U256::one();
@@ -264,6 +294,60 @@ fn test_should_not_mint_or_burn_with_entrypoint_disabled() {
     );
 }
 
+#[test]
+fn should_not_check_rights_with_mint_and_burn_disabled() {
+    let mint_amount = U256::one();
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => false,
+    });
+
+    // Disabled minting and burning are reported before the rights of the caller are checked.
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => mint_amount,
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == 60016),
+        "{:?}",
+        error
+    );
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => mint_amount,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == 60016),
+        "{:?}",
+        error
+    );
+}
+
 #[test]
 fn test_security_no_rights() {
     let mint_amount = U256::one();
@@ -353,6 +437,258 @@ fn test_security_minter_rights() {
     builder.exec(mint_request).commit().expect_success();
 }
 
+#[test]
+fn should_not_mint_without_minter_allowance() {
+    let mint_amount = U256::one();
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+    });
+
+    // A freshly granted minter has no allowance until an Admin sets one.
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {
+            MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        },
+    )
+    .build();
+    builder
+        .exec(change_security_request)
+        .expect_success()
+        .commit();
+
+    let make_mint_request = || {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_1_ADDR,
+            cep18_token,
+            METHOD_MINT,
+            runtime_args! {
+                ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+                ARG_AMOUNT => mint_amount,
+            },
+        )
+        .build()
+    };
+    builder.exec(make_mint_request()).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MINTER_ALLOWANCE_EXCEEDED),
+        "{:?}",
+        error
+    );
+
+    set_minter_allowance(
+        &mut builder,
+        cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        mint_amount,
+    );
+    builder.exec(make_mint_request()).expect_success().commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        mint_amount
+    );
+}
+
+#[test]
+fn test_security_is_minter() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)]
+    });
+
+    assert!(cep18_check_is_minter(
+        &mut builder,
+        &cep18_token,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR)
+    ));
+    assert!(cep18_check_is_minter(
+        &mut builder,
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR)
+    ));
+    assert!(!cep18_check_is_minter(
+        &mut builder,
+        &cep18_token,
+        Key::Account(*ACCOUNT_2_ADDR)
+    ));
+}
+
+#[test]
+fn should_report_badge_of_each_account() {
+    const ADMIN_BADGE: u8 = 0;
+    const MINTER_BADGE: u8 = 1;
+    const NONE_BADGE: u8 = 2;
+    const BURNER_BADGE: u8 = 3;
+    const MINT_AND_BURN_BADGE: u8 = 4;
+    const KYC_ADMIN_BADGE: u8 = 5;
+
+    let none_account = Key::Hash([7u8; 32]);
+    let unknown_account = Key::Hash([8u8; 32]);
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        BURNER_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+        MINT_AND_BURN_LIST => vec![TOKEN_OWNER_ADDRESS_1],
+        KYC_ADMIN_LIST => vec![TOKEN_OWNER_ADDRESS_2],
+        NONE_LIST => vec![none_account],
+    });
+
+    // The Minter has no allowance yet, which `is_minter` does not look at.
+    let expected = [
+        (
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            Some(ADMIN_BADGE),
+            true,
+            true,
+        ),
+        (
+            Key::Account(*ACCOUNT_1_ADDR),
+            Some(MINTER_BADGE),
+            false,
+            true,
+        ),
+        (
+            Key::Account(*ACCOUNT_2_ADDR),
+            Some(BURNER_BADGE),
+            false,
+            false,
+        ),
+        (
+            TOKEN_OWNER_ADDRESS_1,
+            Some(MINT_AND_BURN_BADGE),
+            false,
+            true,
+        ),
+        (TOKEN_OWNER_ADDRESS_2, Some(KYC_ADMIN_BADGE), false, false),
+        (none_account, Some(NONE_BADGE), false, false),
+        (unknown_account, None, false, false),
+    ];
+    for (account, badge, is_admin, is_minter) in expected {
+        assert_eq!(
+            cep18_check_badge(&mut builder, &cep18_token, account),
+            badge,
+            "{:?}",
+            account
+        );
+        assert_eq!(
+            cep18_check_is_admin(&mut builder, &cep18_token, account),
+            is_admin,
+            "{:?}",
+            account
+        );
+        assert_eq!(
+            cep18_check_is_minter(&mut builder, &cep18_token, account),
+            is_minter,
+            "{:?}",
+            account
+        );
+    }
+}
+
+#[test]
+fn should_not_report_admin_as_minter_above_threshold() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        PROPOSAL_THRESHOLD => 2u64,
+    });
+
+    // Admins can only mint through proposals, but stay Admins.
+    assert!(cep18_check_is_admin(
+        &mut builder,
+        &cep18_token,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR)
+    ));
+    assert!(!cep18_check_is_minter(
+        &mut builder,
+        &cep18_token,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR)
+    ));
+}
+
+#[test]
+fn should_not_mint_beyond_minter_allowance() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)]
+    });
+
+    let set_minter_allowance_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_MINTER_ALLOWANCE,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(10),
+        },
+    )
+    .build();
+    builder
+        .exec(set_minter_allowance_request)
+        .expect_success()
+        .commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(6),
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(6),
+        },
+    )
+    .build();
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MINTER_ALLOWANCE_EXCEEDED),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(6)
+    );
+}
+
 #[test]
 fn test_security_burner_rights() {
     let mint_amount = U256::one();
@@ -462,3 +798,373 @@ fn test_change_security() {
         error
     );
 }
+
+#[test]
+fn test_security_delegated_burner_rights() {
+    let burn_amount = U256::one();
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        BURNER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)]
+    });
+
+    // burners cannot mint
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => burn_amount,
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == 60010),
+        "{:?}",
+        error
+    );
+
+    let make_burn_request = |burner| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            burner,
+            cep18_token,
+            METHOD_BURN,
+            runtime_args! {
+                ARG_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_AMOUNT => burn_amount,
+            },
+        )
+        .build()
+    };
+
+    // burners need an allowance from the owner
+    builder.exec(make_burn_request(*ACCOUNT_1_ADDR)).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_ALLOWANCE),
+        "{:?}",
+        error
+    );
+
+    let approve_request = make_cep18_approve_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        burn_amount,
+    );
+    builder.exec(approve_request).expect_success().commit();
+
+    builder
+        .exec(make_burn_request(*ACCOUNT_1_ADDR))
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(
+            &mut builder,
+            &cep18_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(TOKEN_TOTAL_SUPPLY) - burn_amount,
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY) - burn_amount,
+    );
+
+    // the badge adds nothing to the allowance, which other users need as well
+    builder.exec(make_burn_request(*ACCOUNT_2_ADDR)).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_ALLOWANCE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn test_security_mint_and_burn_rights() {
+    let mint_amount = U256::one();
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MINT_AND_BURN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)]
+    });
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => mint_amount,
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => mint_amount,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY),
+    );
+}
+
+#[test]
+fn test_should_not_mint_above_max_supply() {
+    let max_supply = U256::from(TOKEN_TOTAL_SUPPLY) + U256::one();
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MAX_SUPPLY => max_supply,
+    });
+
+    let stored_max_supply: U256 = builder.get_value(cep18_token, MAX_SUPPLY);
+    assert_eq!(stored_max_supply, max_supply);
+
+    let make_mint_request = || {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            METHOD_MINT,
+            runtime_args! {
+                ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+                ARG_AMOUNT => U256::one(),
+            },
+        )
+        .build()
+    };
+
+    builder.exec(make_mint_request()).expect_success().commit();
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        max_supply
+    );
+
+    builder.exec(make_mint_request()).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_EXCEEDS_MAX_SUPPLY),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn test_burn_from_consumes_allowance() {
+    let burn_amount = U256::from(ALLOWANCE_AMOUNT_2);
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+    });
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+
+    let approve_request =
+        make_cep18_approve_request(owner, &cep18_token, spender, U256::from(ALLOWANCE_AMOUNT_1));
+    builder.exec(approve_request).expect_success().commit();
+
+    let burn_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_BURN_FROM,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_AMOUNT => burn_amount,
+        },
+    )
+    .build();
+    builder.exec(burn_from_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY) - burn_amount,
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY) - burn_amount,
+    );
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, spender),
+        U256::from(ALLOWANCE_AMOUNT_1) - burn_amount,
+    );
+
+    let burn_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_BURN_FROM,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_AMOUNT => U256::from(ALLOWANCE_AMOUNT_1),
+        },
+    )
+    .build();
+    builder.exec(burn_from_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_ALLOWANCE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn test_burner_burn_goes_through_burn_from() {
+    let burn_amount = U256::from(ALLOWANCE_AMOUNT_2);
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        BURNER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let burner = Key::Account(*ACCOUNT_1_ADDR);
+    let allowance = burn_amount + burn_amount;
+
+    let approve_request = make_cep18_approve_request(owner, &cep18_token, burner, allowance);
+    builder.exec(approve_request).expect_success().commit();
+
+    for method in [METHOD_BURN, METHOD_BURN_FROM] {
+        let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_1_ADDR,
+            cep18_token,
+            method,
+            runtime_args! {
+                ARG_OWNER => owner,
+                ARG_AMOUNT => burn_amount,
+            },
+        )
+        .build();
+        builder.exec(burn_request).expect_success().commit();
+    }
+
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY) - allowance,
+    );
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, burner),
+        U256::zero(),
+    );
+
+    // Both entry points spend the same exhausted allowance.
+    for method in [METHOD_BURN, METHOD_BURN_FROM] {
+        let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_1_ADDR,
+            cep18_token,
+            method,
+            runtime_args! {
+                ARG_OWNER => owner,
+                ARG_AMOUNT => burn_amount,
+            },
+        )
+        .build();
+        builder.exec(burn_request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_ALLOWANCE),
+            "{:?}",
+            error
+        );
+    }
+}
+
+#[test]
+fn test_batch_mint_tokens() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)]
+    });
+
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_BATCH_MINT,
+        runtime_args! {
+            ARG_RECIPIENTS => vec![TOKEN_OWNER_ADDRESS_1, TOKEN_OWNER_ADDRESS_2],
+            ARG_AMOUNTS => vec![U256::from(TOKEN_OWNER_AMOUNT_1), U256::from(TOKEN_OWNER_AMOUNT_2)],
+        },
+    )
+    .build();
+    builder.exec(batch_mint_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(TOKEN_OWNER_AMOUNT_1)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_2),
+        U256::from(TOKEN_OWNER_AMOUNT_2)
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY + TOKEN_OWNER_AMOUNT_1 + TOKEN_OWNER_AMOUNT_2)
+    );
+
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        cep18_token,
+        METHOD_BATCH_MINT,
+        runtime_args! {
+            ARG_RECIPIENTS => vec![TOKEN_OWNER_ADDRESS_1],
+            ARG_AMOUNTS => vec![U256::one()],
+        },
+    )
+    .build();
+    builder.exec(batch_mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == 60010),
+        "{:?}",
+        error
+    );
+}
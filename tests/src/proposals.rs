@@ -0,0 +1,245 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ADMIN_LIST, ARG_ACTION, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME,
+        ARG_OWNER, ARG_PROPOSAL_ID, ARG_SYMBOL, ARG_TOTAL_SUPPLY, CHANGE_SECURITY,
+        ENABLE_MINT_BURN, ERROR_INSUFFICIENT_RIGHTS, ERROR_NOT_PROPOSER,
+        ERROR_PROPOSAL_ALREADY_EXECUTED, ERROR_PROPOSAL_CANCELLED, ERROR_PROPOSAL_REQUIRED,
+        METHOD_APPROVE_PROPOSAL, METHOD_CANCEL_PROPOSAL, METHOD_MINT, METHOD_PAUSE, METHOD_PROPOSE,
+        MINTER_LIST, NONE_LIST, PROPOSAL_ACTION_CHANGE_SECURITY, PROPOSAL_ACTION_MINT,
+        PROPOSAL_THRESHOLD, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, cep18_check_is_minter, setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+fn assert_user_error(error: CoreError, expected_error: u16) {
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected_error),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_mint_once_proposal_reaches_threshold() {
+    let mint_amount = U256::from(1_000u64);
+
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        PROPOSAL_THRESHOLD => 2u64,
+    });
+
+    // A single Admin can no longer mint on its own.
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => mint_amount,
+        },
+    )
+    .build();
+    builder.exec(mint_request).commit();
+    assert_user_error(
+        builder.get_error().expect("should have error"),
+        ERROR_INSUFFICIENT_RIGHTS,
+    );
+
+    let propose_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PROPOSE,
+        runtime_args! {
+            ARG_ACTION => PROPOSAL_ACTION_MINT,
+            ARG_OWNER => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => mint_amount,
+        },
+    )
+    .build();
+    builder.exec(propose_request).expect_success().commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::zero()
+    );
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_APPROVE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 0u64},
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        mint_amount
+    );
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_APPROVE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 0u64},
+    )
+    .build();
+    builder.exec(approve_request).commit();
+    assert_user_error(
+        builder.get_error().expect("should have error"),
+        ERROR_PROPOSAL_ALREADY_EXECUTED,
+    );
+}
+
+#[test]
+fn should_require_proposal_to_change_security_above_threshold() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        PROPOSAL_THRESHOLD => 2u64,
+    });
+
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {MINTER_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)]},
+    )
+    .build();
+    builder.exec(change_security_request).commit();
+    assert_user_error(
+        builder.get_error().expect("should have error"),
+        ERROR_PROPOSAL_REQUIRED,
+    );
+}
+
+#[test]
+fn should_leave_executing_admin_out_of_change_security_proposal() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        PROPOSAL_THRESHOLD => 2u64,
+    });
+
+    let propose_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PROPOSE,
+        runtime_args! {
+            ARG_ACTION => PROPOSAL_ACTION_CHANGE_SECURITY,
+            MINTER_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+            NONE_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        },
+    )
+    .build();
+    builder.exec(propose_request).expect_success().commit();
+
+    // As with `change_security`, the Admin carrying the change out keeps its own badge.
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_APPROVE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 0u64},
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+    assert!(cep18_check_is_minter(
+        &mut builder,
+        &cep18_token,
+        Key::Account(*ACCOUNT_2_ADDR)
+    ));
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_PAUSE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(pause_request).expect_success().commit();
+}
+
+#[test]
+fn should_only_let_proposer_cancel_proposal() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        PROPOSAL_THRESHOLD => 2u64,
+    });
+
+    let propose_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PROPOSE,
+        runtime_args! {
+            ARG_ACTION => PROPOSAL_ACTION_MINT,
+            ARG_OWNER => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+    builder.exec(propose_request).expect_success().commit();
+
+    let cancel_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_CANCEL_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 0u64},
+    )
+    .build();
+    builder.exec(cancel_request).commit();
+    assert_user_error(
+        builder.get_error().expect("should have error"),
+        ERROR_NOT_PROPOSER,
+    );
+
+    let cancel_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CANCEL_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 0u64},
+    )
+    .build();
+    builder.exec(cancel_request).expect_success().commit();
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_APPROVE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 0u64},
+    )
+    .build();
+    builder.exec(approve_request).commit();
+    assert_user_error(
+        builder.get_error().expect("should have error"),
+        ERROR_PROPOSAL_CANCELLED,
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::zero()
+    );
+}
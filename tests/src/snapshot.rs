@@ -0,0 +1,167 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_ADDRESS, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_OWNER,
+        ARG_SNAPSHOT_ID, ARG_SYMBOL, ARG_TOTAL_SUPPLY, ENABLE_MINT_BURN, ERROR_INVALID_SNAPSHOT_ID,
+        METHOD_BALANCE_OF_AT, METHOD_MINT, METHOD_SNAPSHOT, TOKEN_DECIMALS, TOKEN_NAME,
+        TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of_at, cep18_check_total_supply_at, make_cep18_transfer_request, setup,
+        setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_read_balances_and_total_supply_at_snapshots() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let total_supply = U256::from(TOKEN_TOTAL_SUPPLY);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+    let mint_amount = U256::from(42);
+
+    let snapshot_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SNAPSHOT,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(snapshot_request).expect_success().commit();
+
+    let transfer_request =
+        make_cep18_transfer_request(owner, &cep18_token, account_1, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {ARG_OWNER => account_1, ARG_AMOUNT => mint_amount},
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    let snapshot_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SNAPSHOT,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(snapshot_request).expect_success().commit();
+
+    let transfer_request =
+        make_cep18_transfer_request(owner, &cep18_token, account_1, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of_at(&mut builder, &cep18_token, owner, 1),
+        total_supply
+    );
+    assert_eq!(
+        cep18_check_balance_of_at(&mut builder, &cep18_token, account_1, 1),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_total_supply_at(&mut builder, &cep18_token, 1),
+        total_supply
+    );
+
+    assert_eq!(
+        cep18_check_balance_of_at(&mut builder, &cep18_token, owner, 2),
+        total_supply - transfer_amount
+    );
+    assert_eq!(
+        cep18_check_balance_of_at(&mut builder, &cep18_token, account_1, 2),
+        transfer_amount + mint_amount
+    );
+    assert_eq!(
+        cep18_check_total_supply_at(&mut builder, &cep18_token, 2),
+        total_supply + mint_amount
+    );
+}
+
+#[test]
+fn should_read_balances_between_recorded_snapshots() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let total_supply = U256::from(TOKEN_TOTAL_SUPPLY);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+
+    // Balances only change after snapshots 1 and 3.
+    for snapshot_id in 1..=5u64 {
+        let snapshot_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            METHOD_SNAPSHOT,
+            runtime_args! {},
+        )
+        .build();
+        builder.exec(snapshot_request).expect_success().commit();
+
+        if snapshot_id == 1 || snapshot_id == 3 {
+            let transfer_request =
+                make_cep18_transfer_request(owner, &cep18_token, account_1, transfer_amount);
+            builder.exec(transfer_request).expect_success().commit();
+        }
+    }
+
+    let expected_balances = [
+        (1, U256::zero()),
+        (2, transfer_amount),
+        (3, transfer_amount),
+        (4, transfer_amount * 2),
+        (5, transfer_amount * 2),
+    ];
+    for (snapshot_id, balance) in expected_balances {
+        assert_eq!(
+            cep18_check_balance_of_at(&mut builder, &cep18_token, account_1, snapshot_id),
+            balance,
+            "balance at snapshot {}",
+            snapshot_id
+        );
+        assert_eq!(
+            cep18_check_total_supply_at(&mut builder, &cep18_token, snapshot_id),
+            total_supply
+        );
+    }
+}
+
+#[test]
+fn should_not_read_balance_at_future_snapshot() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let balance_of_at_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_BALANCE_OF_AT,
+        runtime_args! {
+            ARG_ADDRESS => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_SNAPSHOT_ID => 1u64,
+        },
+    )
+    .build();
+    builder.exec(balance_of_at_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_SNAPSHOT_ID),
+        "{:?}",
+        error
+    );
+}
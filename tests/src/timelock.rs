@@ -0,0 +1,356 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, ApiError, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ADMIN_LIST, ARG_ACTION, ARG_DECIMALS, ARG_NAME,
+        ARG_OPERATION_ID, ARG_PROPOSAL_ID, ARG_SYMBOL, ARG_THRESHOLD, ARG_TOTAL_SUPPLY,
+        CEP18_CONTRACT_WASM, CEP18_TOKEN_CONTRACT_KEY, CHANGE_SECURITY, ENABLE_MINT_BURN,
+        ERROR_OPERATION_NOT_PENDING, ERROR_OPERATION_NOT_READY, ERROR_PROPOSAL_REQUIRED,
+        ERROR_TIMELOCK_REQUIRED, ERROR_UPGRADE_NOT_SCHEDULED, METHOD_APPROVE_PROPOSAL,
+        METHOD_CANCEL, METHOD_EXECUTE, METHOD_PROPOSE, METHOD_SCHEDULE, MINTER_LIST,
+        PROPOSAL_ACTION_CANCEL_OPERATION, PROPOSAL_ACTION_CHANGE_SECURITY,
+        PROPOSAL_ACTION_SET_THRESHOLD, PROPOSAL_ACTION_UPGRADE, PROPOSAL_THRESHOLD, TIMELOCK_DELAY,
+        TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{cep18_check_is_minter, setup_with_args, TestContext},
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+fn assert_user_error(error: CoreError, expected_error: u16) {
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected_error),
+        "{:?}",
+        error
+    );
+}
+
+fn setup_with_timelock() -> (InMemoryWasmTestBuilder, TestContext) {
+    setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        TIMELOCK_DELAY => 1_000u64,
+    })
+}
+
+#[test]
+fn should_change_security_once_timelock_delay_passed() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_timelock();
+
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)]},
+    )
+    .build();
+    builder.exec(change_security_request).commit();
+    assert_user_error(
+        builder.get_error().expect("should have error"),
+        ERROR_TIMELOCK_REQUIRED,
+    );
+
+    let schedule_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SCHEDULE,
+        runtime_args! {
+            ARG_ACTION => PROPOSAL_ACTION_CHANGE_SECURITY,
+            MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(schedule_request).expect_success().commit();
+
+    let early_execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_EXECUTE,
+        runtime_args! {ARG_OPERATION_ID => 0u64},
+    )
+    .with_block_time(1_999)
+    .build();
+    builder.exec(early_execute_request).commit();
+    assert_user_error(
+        builder.get_error().expect("should have error"),
+        ERROR_OPERATION_NOT_READY,
+    );
+    assert!(!cep18_check_is_minter(
+        &mut builder,
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR)
+    ));
+
+    let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_EXECUTE,
+        runtime_args! {ARG_OPERATION_ID => 0u64},
+    )
+    .with_block_time(2_000)
+    .build();
+    builder.exec(execute_request).expect_success().commit();
+    assert!(cep18_check_is_minter(
+        &mut builder,
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR)
+    ));
+}
+
+#[test]
+fn should_not_execute_cancelled_operation() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_timelock();
+
+    let schedule_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SCHEDULE,
+        runtime_args! {
+            ARG_ACTION => PROPOSAL_ACTION_CHANGE_SECURITY,
+            MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(schedule_request).expect_success().commit();
+
+    let cancel_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CANCEL,
+        runtime_args! {ARG_OPERATION_ID => 0u64},
+    )
+    .build();
+    builder.exec(cancel_request).expect_success().commit();
+
+    let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_EXECUTE,
+        runtime_args! {ARG_OPERATION_ID => 0u64},
+    )
+    .with_block_time(2_000)
+    .build();
+    builder.exec(execute_request).commit();
+    assert_user_error(
+        builder.get_error().expect("should have error"),
+        ERROR_OPERATION_NOT_PENDING,
+    );
+}
+
+#[test]
+fn should_upgrade_once_upgrade_operation_executed() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_timelock();
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {ARG_NAME => TOKEN_NAME},
+    )
+    .build();
+    builder.exec(upgrade_request).commit();
+    assert_user_error(
+        builder.get_error().expect("should have error"),
+        ERROR_UPGRADE_NOT_SCHEDULED,
+    );
+
+    let schedule_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SCHEDULE,
+        runtime_args! {ARG_ACTION => PROPOSAL_ACTION_UPGRADE},
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(schedule_request).expect_success().commit();
+
+    let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_EXECUTE,
+        runtime_args! {ARG_OPERATION_ID => 0u64},
+    )
+    .with_block_time(2_000)
+    .build();
+    builder.exec(execute_request).expect_success().commit();
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {ARG_NAME => TOKEN_NAME},
+    )
+    .build();
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let upgraded_token = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CEP18_TOKEN_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+    assert_ne!(upgraded_token, cep18_token);
+
+    // The executed operation only covers one upgrade.
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {ARG_NAME => TOKEN_NAME},
+    )
+    .build();
+    builder.exec(upgrade_request).commit();
+    assert_user_error(
+        builder.get_error().expect("should have error"),
+        ERROR_UPGRADE_NOT_SCHEDULED,
+    );
+}
+
+#[test]
+fn should_schedule_lowering_of_threshold() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        PROPOSAL_THRESHOLD => 2u64,
+        TIMELOCK_DELAY => 1_000u64,
+    });
+
+    let propose_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PROPOSE,
+        runtime_args! {
+            ARG_ACTION => PROPOSAL_ACTION_SET_THRESHOLD,
+            ARG_THRESHOLD => 1u64,
+        },
+    )
+    .build();
+    builder.exec(propose_request).expect_success().commit();
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_APPROVE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 0u64},
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+    let threshold: u64 = builder.get_value(cep18_token, PROPOSAL_THRESHOLD);
+    assert_eq!(threshold, 2);
+
+    let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_EXECUTE,
+        runtime_args! {ARG_OPERATION_ID => 0u64},
+    )
+    .with_block_time(2_000)
+    .build();
+    builder.exec(execute_request).expect_success().commit();
+    let threshold: u64 = builder.get_value(cep18_token, PROPOSAL_THRESHOLD);
+    assert_eq!(threshold, 1);
+}
+
+#[test]
+fn should_cancel_operation_through_proposal_above_threshold() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ENABLE_MINT_BURN => true,
+        ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        PROPOSAL_THRESHOLD => 2u64,
+        TIMELOCK_DELAY => 1_000u64,
+    });
+
+    let propose_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_PROPOSE,
+        runtime_args! {
+            ARG_ACTION => PROPOSAL_ACTION_CHANGE_SECURITY,
+            MINTER_LIST => vec![Key::Account(*ACCOUNT_2_ADDR)],
+        },
+    )
+    .build();
+    builder.exec(propose_request).expect_success().commit();
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_APPROVE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 0u64},
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    // A single Admin can no longer drop the operation on its own.
+    let cancel_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_CANCEL,
+        runtime_args! {ARG_OPERATION_ID => 0u64},
+    )
+    .build();
+    builder.exec(cancel_request).commit();
+    assert_user_error(
+        builder.get_error().expect("should have error"),
+        ERROR_PROPOSAL_REQUIRED,
+    );
+
+    let propose_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_PROPOSE,
+        runtime_args! {
+            ARG_ACTION => PROPOSAL_ACTION_CANCEL_OPERATION,
+            ARG_OPERATION_ID => 0u64,
+        },
+    )
+    .build();
+    builder.exec(propose_request).expect_success().commit();
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_APPROVE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 1u64},
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_EXECUTE,
+        runtime_args! {ARG_OPERATION_ID => 0u64},
+    )
+    .with_block_time(2_000)
+    .build();
+    builder.exec(execute_request).commit();
+    assert_user_error(
+        builder.get_error().expect("should have error"),
+        ERROR_OPERATION_NOT_PENDING,
+    );
+    assert!(!cep18_check_is_minter(
+        &mut builder,
+        &cep18_token,
+        Key::Account(*ACCOUNT_2_ADDR)
+    ));
+}
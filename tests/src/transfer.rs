@@ -3,10 +3,12 @@ use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
 
 use crate::utility::{
     constants::{
-        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ALLOWANCE_AMOUNT_1, ARG_AMOUNT, ARG_OWNER, ARG_RECIPIENT,
-        ARG_SPENDER, ARG_TOKEN_CONTRACT, ERROR_INSUFFICIENT_BALANCE, METHOD_APPROVE,
-        METHOD_FROM_AS_STORED_CONTRACT, METHOD_TRANSFER, METHOD_TRANSFER_FROM, TOKEN_TOTAL_SUPPLY,
-        TOTAL_SUPPLY_KEY, TRANSFER_AMOUNT_1,
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ALLOWANCE_AMOUNT_1, ARG_AMOUNT, ARG_AMOUNTS, ARG_OWNER,
+        ARG_RECIPIENT, ARG_RECIPIENTS, ARG_SPENDER, ARG_TOKEN_CONTRACT,
+        ERROR_BATCH_LENGTH_MISMATCH, ERROR_INSUFFICIENT_BALANCE, METHOD_APPROVE,
+        METHOD_BATCH_TRANSFER, METHOD_FROM_AS_STORED_CONTRACT, METHOD_TRANSFER,
+        METHOD_TRANSFER_FROM, TOKEN_TOTAL_SUPPLY, TOTAL_SUPPLY_KEY, TRANSFER_AMOUNT_1,
+        TRANSFER_AMOUNT_2,
     },
     installer_request_builders::{
         cep18_check_allowance_of, cep18_check_balance_of, make_cep18_approve_request,
@@ -526,3 +528,99 @@ fn should_transfer_account_to_account() {
         recipient2,
     );
 }
+
+#[test]
+fn should_batch_transfer_to_several_recipients() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let recipients = vec![
+        Key::Account(*ACCOUNT_1_ADDR),
+        Key::Account(*ACCOUNT_2_ADDR),
+        Key::Hash([42; 32]),
+    ];
+    let amounts = vec![
+        U256::from(TRANSFER_AMOUNT_1),
+        U256::from(TRANSFER_AMOUNT_2),
+        U256::one(),
+    ];
+
+    let batch_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_BATCH_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENTS => recipients.clone(),
+            ARG_AMOUNTS => amounts.clone(),
+        },
+    )
+    .build();
+    builder
+        .exec(batch_transfer_request)
+        .expect_success()
+        .commit();
+
+    for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+        assert_eq!(
+            cep18_check_balance_of(&mut builder, &cep18_token, *recipient),
+            *amount
+        );
+    }
+
+    let total_amount = amounts
+        .iter()
+        .fold(U256::zero(), |total, amount| total + *amount);
+    assert_eq!(
+        cep18_check_balance_of(
+            &mut builder,
+            &cep18_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(TOKEN_TOTAL_SUPPLY) - total_amount
+    );
+
+    let total_supply: U256 = builder.get_value(cep18_token, TOTAL_SUPPLY_KEY);
+    assert_eq!(total_supply, U256::from(TOKEN_TOTAL_SUPPLY));
+}
+
+#[test]
+fn should_not_batch_transfer_more_than_owned_balance() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let batch_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_BATCH_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENTS => vec![Key::Account(*ACCOUNT_1_ADDR), Key::Account(*ACCOUNT_2_ADDR)],
+            ARG_AMOUNTS => vec![U256::from(TOKEN_TOTAL_SUPPLY), U256::one()],
+        },
+    )
+    .build();
+    builder.exec(batch_transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_BALANCE),
+        "{:?}",
+        error
+    );
+
+    let batch_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_BATCH_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENTS => vec![Key::Account(*ACCOUNT_1_ADDR), Key::Account(*ACCOUNT_2_ADDR)],
+            ARG_AMOUNTS => vec![U256::one()],
+        },
+    )
+    .build();
+    builder.exec(batch_transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_BATCH_LENGTH_MISMATCH),
+        "{:?}",
+        error
+    );
+}
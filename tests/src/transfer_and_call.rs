@@ -0,0 +1,169 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{bytesrepr::Bytes, runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ALLOWANCE_AMOUNT_1, ARG_AMOUNT, ARG_DATA, ARG_OWNER, ARG_RECIPIENT,
+        ERROR_RECIPIENT_NOT_CONTRACT, ERROR_TRANSFER_REJECTED, METHOD_TRANSFER_AND_CALL,
+        METHOD_TRANSFER_FROM_AND_CALL, REJECT_DATA, TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1,
+    },
+    installer_request_builders::{
+        cep18_check_allowance_of, cep18_check_balance_of, get_test_result,
+        make_cep18_approve_request, setup, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_transfer_and_notify_recipient_contract() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup();
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Hash(cep18_test_contract_package.value());
+    let amount = U256::from(TRANSFER_AMOUNT_1);
+
+    let transfer_and_call_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER_AND_CALL,
+        runtime_args! {
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => amount,
+            ARG_DATA => Bytes::from(b"deposit".to_vec()),
+        },
+    )
+    .build();
+    builder
+        .exec(transfer_and_call_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        amount
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, sender),
+        U256::from(TOKEN_TOTAL_SUPPLY) - amount
+    );
+    let (operator, from, received): (Key, Key, U256) =
+        get_test_result(&mut builder, cep18_test_contract_package);
+    assert_eq!((operator, from, received), (sender, sender, amount));
+}
+
+#[test]
+fn should_revert_transfer_rejected_by_recipient() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup();
+    let recipient = Key::Hash(cep18_test_contract_package.value());
+
+    let transfer_and_call_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER_AND_CALL,
+        runtime_args! {
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_1),
+            ARG_DATA => Bytes::from(REJECT_DATA.to_vec()),
+        },
+    )
+    .build();
+    builder.exec(transfer_and_call_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_TRANSFER_REJECTED),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_not_transfer_and_call_to_account() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let transfer_and_call_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER_AND_CALL,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_1),
+            ARG_DATA => Bytes::new(),
+        },
+    )
+    .build();
+    builder.exec(transfer_and_call_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECIPIENT_NOT_CONTRACT),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_transfer_from_and_notify_recipient_contract() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+    let recipient = Key::Hash(cep18_test_contract_package.value());
+    let allowance = U256::from(ALLOWANCE_AMOUNT_1);
+    let amount = U256::from(TRANSFER_AMOUNT_1);
+
+    let approve_request = make_cep18_approve_request(owner, &cep18_token, spender, allowance);
+    builder.exec(approve_request).expect_success().commit();
+
+    let transfer_from_and_call_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_TRANSFER_FROM_AND_CALL,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => amount,
+            ARG_DATA => Bytes::new(),
+        },
+    )
+    .build();
+    builder
+        .exec(transfer_from_and_call_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        amount
+    );
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, spender),
+        allowance - amount
+    );
+    let (operator, from, received): (Key, Key, U256) =
+        get_test_result(&mut builder, cep18_test_contract_package);
+    assert_eq!((operator, from, received), (spender, owner, amount));
+}
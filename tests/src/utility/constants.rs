@@ -1,11 +1,20 @@
 use casper_types::{account::AccountHash, Key, PublicKey, SecretKey};
+use cep18_types::Cep18Error;
 use once_cell::sync::Lazy;
 
 pub const CEP18_CONTRACT_WASM: &str = "cep18.wasm";
 pub const CEP18_TEST_CONTRACT_WASM: &str = "cep18_test_contract.wasm";
+pub const CEP18_BASELINE_CONTRACT_WASM: &str = "cep18_baseline.wasm";
+pub const CEP18_EXAMPLE_CONTRACT_WASM: &str = "cep18_example.wasm";
 pub const NAME_KEY: &str = "name";
 pub const SYMBOL_KEY: &str = "symbol";
 pub const CEP18_TOKEN_CONTRACT_KEY: &str = "cep18_contract_hash_CasperTest";
+pub const CEP18_TOKEN_CONTRACT_VERSION_KEY: &str = "cep18_contract_version_CasperTest";
+pub const CEP18_TOKEN_CONTRACT_PACKAGE_KEY: &str = "cep18_contract_package_CasperTest";
+pub const HOLDER_COUNT_KEY: &str = "holder_count";
+pub const HOLDERS_KEY: &str = "holders";
+pub const ROLE_MEMBERS_KEY: &str = "role_members";
+pub const ROLE_MEMBER_COUNTS_KEY: &str = "role_member_counts";
 pub const DECIMALS_KEY: &str = "decimals";
 pub const TOTAL_SUPPLY_KEY: &str = "total_supply";
 pub const BALANCES_KEY: &str = "balances";
@@ -18,10 +27,11 @@ pub const ARG_SYMBOL: &str = "symbol";
 pub const ARG_DECIMALS: &str = "decimals";
 pub const ARG_TOTAL_SUPPLY: &str = "total_supply";
 
-pub const _ERROR_INVALID_CONTEXT: u16 = 60000;
-pub const ERROR_INSUFFICIENT_BALANCE: u16 = 60001;
-pub const ERROR_INSUFFICIENT_ALLOWANCE: u16 = 60002;
-pub const ERROR_OVERFLOW: u16 = 60003;
+pub const _ERROR_INVALID_CONTEXT: u16 = Cep18Error::InvalidContext as u16;
+pub const ERROR_INSUFFICIENT_BALANCE: u16 = Cep18Error::InsufficientBalance as u16;
+pub const ERROR_INSUFFICIENT_ALLOWANCE: u16 = Cep18Error::InsufficientAllowance as u16;
+pub const ERROR_INSUFFICIENT_RIGHTS: u16 = Cep18Error::InsufficientRights as u16;
+pub const ERROR_OVERFLOW: u16 = Cep18Error::Overflow as u16;
 
 pub const TOKEN_NAME: &str = "CasperTest";
 pub const TOKEN_SYMBOL: &str = "CSPRT";
@@ -29,6 +39,7 @@ pub const TOKEN_DECIMALS: u8 = 100;
 pub const TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
 
 pub const METHOD_TRANSFER: &str = "transfer";
+pub const METHOD_AIRDROP: &str = "airdrop";
 pub const ARG_AMOUNT: &str = "amount";
 pub const ARG_RECIPIENT: &str = "recipient";
 
@@ -37,12 +48,65 @@ pub const ARG_OWNER: &str = "owner";
 pub const ARG_SPENDER: &str = "spender";
 
 pub const METHOD_TRANSFER_FROM: &str = "transfer_from";
+pub const METHOD_BATCH_TRANSFER: &str = "batch_transfer";
+pub const ARG_RECIPIENTS: &str = "recipients";
+pub const ARG_AMOUNTS: &str = "amounts";
+pub const ARG_OWNER_PUBLIC_KEY: &str = "owner_public_key";
+pub const ARG_DEADLINE: &str = "deadline";
+pub const ARG_NONCE: &str = "nonce";
+pub const ARG_SIGNATURE: &str = "signature";
+pub const ARG_EXPIRES_AT: &str = "expires_at";
+pub const METHOD_TRANSFER_AND_CALL: &str = "transfer_and_call";
+pub const METHOD_TRANSFER_FROM_AND_CALL: &str = "transfer_from_and_call";
+pub const ARG_DATA: &str = "data";
+pub const REJECT_DATA: &[u8] = b"reject";
+pub const ERROR_BATCH_LENGTH_MISMATCH: u16 = Cep18Error::BatchLengthMismatch as u16;
+pub const ERROR_PERMIT_EXPIRED: u16 = Cep18Error::PermitExpired as u16;
+pub const ERROR_INVALID_NONCE: u16 = Cep18Error::InvalidNonce as u16;
+pub const ERROR_INVALID_SIGNATURE: u16 = Cep18Error::InvalidSignature as u16;
+pub const ERROR_INVALID_SNAPSHOT_ID: u16 = Cep18Error::InvalidSnapshotId as u16;
+pub const ERROR_FUTURE_LOOKUP: u16 = Cep18Error::FutureLookup as u16;
+pub const ERROR_LAST_ADMIN: u16 = Cep18Error::LastAdmin as u16;
+pub const ERROR_NOT_PENDING_ADMIN: u16 = Cep18Error::NotPendingAdmin as u16;
+pub const ERROR_INVALID_PROPOSAL_THRESHOLD: u16 = Cep18Error::InvalidProposalThreshold as u16;
+pub const ERROR_ROLE_MEMBER_INDEX_OUT_OF_RANGE: u16 = Cep18Error::RoleMemberIndexOutOfRange as u16;
+pub const ERROR_HOLDER_INDEX_OUT_OF_RANGE: u16 = Cep18Error::HolderIndexOutOfRange as u16;
+pub const ERROR_ADMIN_GRANT_NOT_ALLOWED: u16 = Cep18Error::AdminGrantNotAllowed as u16;
+pub const ERROR_PROPOSAL_REQUIRED: u16 = Cep18Error::ProposalRequired as u16;
+pub const ERROR_PROPOSAL_ALREADY_EXECUTED: u16 = Cep18Error::ProposalAlreadyExecuted as u16;
+pub const ERROR_PROPOSAL_CANCELLED: u16 = Cep18Error::ProposalCancelled as u16;
+pub const ERROR_NOT_PROPOSER: u16 = Cep18Error::NotProposer as u16;
+pub const ERROR_TIMELOCK_REQUIRED: u16 = Cep18Error::TimelockRequired as u16;
+pub const ERROR_OPERATION_NOT_PENDING: u16 = Cep18Error::OperationNotPending as u16;
+pub const ERROR_OPERATION_NOT_READY: u16 = Cep18Error::OperationNotReady as u16;
+pub const ERROR_UPGRADE_NOT_SCHEDULED: u16 = Cep18Error::UpgradeNotScheduled as u16;
+pub const ERROR_MINTER_ALLOWANCE_EXCEEDED: u16 = Cep18Error::MinterAllowanceExceeded as u16;
+pub const ERROR_RECIPIENT_NOT_CONTRACT: u16 = Cep18Error::RecipientNotContract as u16;
+pub const ERROR_TRANSFER_REJECTED: u16 = Cep18Error::TransferRejected as u16;
 
 pub const CHECK_TOTAL_SUPPLY_ENTRYPOINT: &str = "check_total_supply";
 pub const CHECK_BALANCE_OF_ENTRYPOINT: &str = "check_balance_of";
 pub const CHECK_ALLOWANCE_OF_ENTRYPOINT: &str = "check_allowance_of";
+pub const CHECK_BALANCE_OF_AT_ENTRYPOINT: &str = "check_balance_of_at";
+pub const CHECK_TOTAL_SUPPLY_AT_ENTRYPOINT: &str = "check_total_supply_at";
+pub const CHECK_VOTES_ENTRYPOINT: &str = "check_votes";
+pub const CHECK_PAST_VOTES_ENTRYPOINT: &str = "check_past_votes";
+pub const CHECK_IS_MINTER_ENTRYPOINT: &str = "check_is_minter";
+pub const CHECK_IS_ADMIN_ENTRYPOINT: &str = "check_is_admin";
+pub const CHECK_BADGE_ENTRYPOINT: &str = "check_badge";
+pub const CHECK_ROLE_MEMBER_COUNT_ENTRYPOINT: &str = "check_role_member_count";
+pub const CHECK_ROLE_MEMBER_AT_ENTRYPOINT: &str = "check_role_member_at";
+pub const CHECK_HOLDER_COUNT_ENTRYPOINT: &str = "check_holder_count";
+pub const CHECK_HOLDER_AT_ENTRYPOINT: &str = "check_holder_at";
 pub const ARG_TOKEN_CONTRACT: &str = "token_contract";
 pub const ARG_ADDRESS: &str = "address";
+pub const ARG_SNAPSHOT_ID: &str = "snapshot_id";
+pub const ARG_DELEGATEE: &str = "delegatee";
+pub const ARG_ACCOUNT: &str = "account";
+pub const ARG_TIMESTAMP: &str = "timestamp";
+pub const ARG_ACTION: &str = "action";
+pub const ARG_PROPOSAL_ID: &str = "proposal_id";
+pub const ARG_OPERATION_ID: &str = "operation_id";
 pub const RESULT_KEY: &str = "result";
 pub const CEP18_TEST_CONTRACT_KEY: &str = "cep18_test_contract";
 
@@ -74,10 +138,58 @@ pub const TOKEN_OWNER_AMOUNT_2: u64 = 2_000_000;
 
 pub const METHOD_MINT: &str = "mint";
 pub const METHOD_BURN: &str = "burn";
+pub const METHOD_BATCH_MINT: &str = "batch_mint";
+pub const METHOD_SET_MINTER_ALLOWANCE: &str = "set_minter_allowance";
+pub const METHOD_PERMIT: &str = "permit";
+pub const METHOD_SNAPSHOT: &str = "snapshot";
+pub const METHOD_BALANCE_OF_AT: &str = "balance_of_at";
+pub const METHOD_DELEGATE: &str = "delegate";
+pub const METHOD_GET_PAST_VOTES: &str = "get_past_votes";
+pub const METHOD_PROPOSE_ADMIN: &str = "propose_admin";
+pub const METHOD_INDEX_ACCOUNTS: &str = "index_accounts";
+pub const ARG_ACCOUNTS: &str = "accounts";
+pub const ARG_BADGE: &str = "badge";
+pub const ARG_INDEX: &str = "index";
+pub const METHOD_ACCEPT_ADMIN: &str = "accept_admin";
+pub const METHOD_PROPOSE: &str = "propose";
+pub const METHOD_APPROVE_PROPOSAL: &str = "approve_proposal";
+pub const METHOD_CANCEL_PROPOSAL: &str = "cancel_proposal";
+pub const PROPOSAL_THRESHOLD: &str = "proposal_threshold";
+pub const PROPOSAL_ACTION_CHANGE_SECURITY: u8 = 0;
+pub const PROPOSAL_ACTION_MINT: u8 = 1;
+pub const PROPOSAL_ACTION_SET_THRESHOLD: u8 = 4;
+pub const PROPOSAL_ACTION_UPGRADE: u8 = 7;
+pub const PROPOSAL_ACTION_PROPOSE_ADMIN: u8 = 8;
+pub const PROPOSAL_ACTION_CANCEL_OPERATION: u8 = 9;
+pub const ARG_THRESHOLD: &str = "threshold";
+pub const METHOD_SCHEDULE: &str = "schedule";
+pub const METHOD_EXECUTE: &str = "execute";
+pub const METHOD_CANCEL: &str = "cancel";
+pub const TIMELOCK_DELAY: &str = "timelock_delay";
+pub const METHOD_BURN_FROM: &str = "burn_from";
 pub const DECREASE_ALLOWANCE: &str = "decrease_allowance";
 pub const INCREASE_ALLOWANCE: &str = "increase_allowance";
 pub const ENABLE_MINT_BURN: &str = "enable_mint_burn";
 pub const ADMIN_LIST: &str = "admin_list";
 pub const MINTER_LIST: &str = "minter_list";
+pub const BURNER_LIST: &str = "burner_list";
+pub const MINT_AND_BURN_LIST: &str = "mint_and_burn_list";
 pub const NONE_LIST: &str = "none_list";
 pub const CHANGE_SECURITY: &str = "change_security";
+pub const METHOD_PAUSE: &str = "pause";
+pub const METHOD_UNPAUSE: &str = "unpause";
+pub const ERROR_PAUSED: u16 = Cep18Error::Paused as u16;
+pub const METHOD_FREEZE_ACCOUNT: &str = "freeze_account";
+pub const METHOD_UNFREEZE_ACCOUNT: &str = "unfreeze_account";
+pub const ERROR_ACCOUNT_FROZEN: u16 = Cep18Error::AccountFrozen as u16;
+pub const TRANSFER_FILTER_MODE: &str = "transfer_filter_mode";
+pub const KYC_ADMIN_LIST: &str = "kyc_admin_list";
+pub const METHOD_ADD_TO_WHITELIST: &str = "add_to_whitelist";
+pub const METHOD_REMOVE_FROM_WHITELIST: &str = "remove_from_whitelist";
+pub const ERROR_RECIPIENT_NOT_WHITELISTED: u16 = Cep18Error::RecipientNotWhitelisted as u16;
+pub const MAX_SUPPLY: &str = "max_supply";
+pub const ERROR_EXCEEDS_MAX_SUPPLY: u16 = Cep18Error::ExceedsMaxSupply as u16;
+pub const EVENTS_MODE: &str = "events_mode";
+pub const EVENTS_KEY: &str = "__events";
+pub const EVENTS_LENGTH_KEY: &str = "__events_length";
+pub const EVENTS_SCHEMA_KEY: &str = "__events_schema";
@@ -13,13 +13,18 @@ use crate::utility::constants::{
 };
 
 use super::constants::{
-    ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ADDRESS, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_OWNER,
-    ARG_RECIPIENT, ARG_SPENDER, ARG_SYMBOL, ARG_TOKEN_CONTRACT, ARG_TOTAL_SUPPLY,
-    CEP18_CONTRACT_WASM, CEP18_TEST_CONTRACT_KEY, CEP18_TEST_CONTRACT_WASM,
-    CEP18_TOKEN_CONTRACT_KEY, CHECK_ALLOWANCE_OF_ENTRYPOINT, CHECK_BALANCE_OF_ENTRYPOINT,
-    CHECK_TOTAL_SUPPLY_ENTRYPOINT, METHOD_APPROVE, METHOD_APPROVE_AS_STORED_CONTRACT,
-    METHOD_TRANSFER, METHOD_TRANSFER_AS_STORED_CONTRACT, RESULT_KEY, TOKEN_DECIMALS, TOKEN_NAME,
-    TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ACCOUNT, ARG_ADDRESS, ARG_AMOUNT, ARG_BADGE, ARG_DECIMALS,
+    ARG_INDEX, ARG_NAME, ARG_OWNER, ARG_RECIPIENT, ARG_SNAPSHOT_ID, ARG_SPENDER, ARG_SYMBOL,
+    ARG_TIMESTAMP, ARG_TOKEN_CONTRACT, ARG_TOTAL_SUPPLY, CEP18_CONTRACT_WASM,
+    CEP18_TEST_CONTRACT_KEY, CEP18_TEST_CONTRACT_WASM, CEP18_TOKEN_CONTRACT_KEY,
+    CHECK_ALLOWANCE_OF_ENTRYPOINT, CHECK_BADGE_ENTRYPOINT, CHECK_BALANCE_OF_AT_ENTRYPOINT,
+    CHECK_BALANCE_OF_ENTRYPOINT, CHECK_HOLDER_AT_ENTRYPOINT, CHECK_HOLDER_COUNT_ENTRYPOINT,
+    CHECK_IS_ADMIN_ENTRYPOINT, CHECK_IS_MINTER_ENTRYPOINT, CHECK_PAST_VOTES_ENTRYPOINT,
+    CHECK_ROLE_MEMBER_AT_ENTRYPOINT, CHECK_ROLE_MEMBER_COUNT_ENTRYPOINT,
+    CHECK_TOTAL_SUPPLY_AT_ENTRYPOINT, CHECK_TOTAL_SUPPLY_ENTRYPOINT, CHECK_VOTES_ENTRYPOINT,
+    METHOD_APPROVE, METHOD_APPROVE_AS_STORED_CONTRACT, METHOD_TRANSFER,
+    METHOD_TRANSFER_AS_STORED_CONTRACT, RESULT_KEY, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL,
+    TOKEN_TOTAL_SUPPLY,
 };
 
 /// Converts hash addr of Account into Hash, and Hash into Account
@@ -50,6 +55,13 @@ pub(crate) fn setup() -> (InMemoryWasmTestBuilder, TestContext) {
 }
 
 pub(crate) fn setup_with_args(install_args: RuntimeArgs) -> (InMemoryWasmTestBuilder, TestContext) {
+    setup_with_wasm(CEP18_CONTRACT_WASM, install_args)
+}
+
+pub(crate) fn setup_with_wasm(
+    cep18_wasm: &str,
+    install_args: RuntimeArgs,
+) -> (InMemoryWasmTestBuilder, TestContext) {
     let mut builder = InMemoryWasmTestBuilder::default();
     builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
 
@@ -71,8 +83,7 @@ pub(crate) fn setup_with_args(install_args: RuntimeArgs) -> (InMemoryWasmTestBui
         ExecuteRequestBuilder::transfer(*DEFAULT_ACCOUNT_ADDR, transfer_2_args).build();
 
     let install_request_1 =
-        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CEP18_CONTRACT_WASM, install_args)
-            .build();
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, cep18_wasm, install_args).build();
 
     let install_request_2 = ExecuteRequestBuilder::standard(
         *DEFAULT_ACCOUNT_ADDR,
@@ -194,6 +205,375 @@ pub(crate) fn cep18_check_balance_of(
     get_test_result(builder, cep18_test_contract_package)
 }
 
+pub(crate) fn cep18_check_balance_of_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    address: Key,
+    snapshot_id: u64,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_balance_at_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_ADDRESS => address,
+        ARG_SNAPSHOT_ID => snapshot_id,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_BALANCE_OF_AT_ENTRYPOINT,
+        check_balance_at_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_total_supply_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    snapshot_id: u64,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_total_supply_at_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_SNAPSHOT_ID => snapshot_id,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_TOTAL_SUPPLY_AT_ENTRYPOINT,
+        check_total_supply_at_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_votes(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    account: Key,
+) -> U256 {
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = default_account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_votes_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_ACCOUNT => account,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_VOTES_ENTRYPOINT,
+        check_votes_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_past_votes(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    account: Key,
+    timestamp: u64,
+    block_time: u64,
+) -> U256 {
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = default_account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_past_votes_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_ACCOUNT => account,
+        ARG_TIMESTAMP => timestamp,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_PAST_VOTES_ENTRYPOINT,
+        check_past_votes_args,
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_is_minter(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    account: Key,
+) -> bool {
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = default_account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_is_minter_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_ACCOUNT => account,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_IS_MINTER_ENTRYPOINT,
+        check_is_minter_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_is_admin(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    account: Key,
+) -> bool {
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = default_account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_is_admin_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_ACCOUNT => account,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_IS_ADMIN_ENTRYPOINT,
+        check_is_admin_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_badge(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    account: Key,
+) -> Option<u8> {
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = default_account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_badge_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_ACCOUNT => account,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_BADGE_ENTRYPOINT,
+        check_badge_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_role_member_count(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    badge: u8,
+) -> u64 {
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = default_account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_role_member_count_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_BADGE => badge,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_ROLE_MEMBER_COUNT_ENTRYPOINT,
+        check_role_member_count_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_role_member_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    badge: u8,
+    index: u64,
+) -> Key {
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = default_account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_role_member_at_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_BADGE => badge,
+        ARG_INDEX => index,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_ROLE_MEMBER_AT_ENTRYPOINT,
+        check_role_member_at_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_holder_count(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+) -> u64 {
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = default_account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_holder_count_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_HOLDER_COUNT_ENTRYPOINT,
+        check_holder_count_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_holder_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    index: u64,
+) -> Key {
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = default_account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_holder_at_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_INDEX => index,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_HOLDER_AT_ENTRYPOINT,
+        check_holder_at_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
 pub(crate) fn cep18_check_allowance_of(
     builder: &mut InMemoryWasmTestBuilder,
     owner: Key,
@@ -0,0 +1,189 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ACCOUNT, ARG_AMOUNT, ARG_DECIMALS, ARG_DELEGATEE,
+        ARG_NAME, ARG_RECIPIENT, ARG_SYMBOL, ARG_TIMESTAMP, ARG_TOTAL_SUPPLY, ERROR_FUTURE_LOOKUP,
+        EVENTS_LENGTH_KEY, EVENTS_MODE, METHOD_DELEGATE, METHOD_GET_PAST_VOTES, METHOD_TRANSFER,
+        TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1,
+    },
+    installer_request_builders::{
+        cep18_check_past_votes, cep18_check_votes, make_cep18_transfer_request, setup,
+        setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_move_votes_with_delegation_and_transfers() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2 = Key::Account(*ACCOUNT_2_ADDR);
+    let total_supply = U256::from(TOKEN_TOTAL_SUPPLY);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+
+    assert_eq!(
+        cep18_check_votes(&mut builder, &cep18_token, account_1),
+        U256::zero()
+    );
+
+    let delegate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_DELEGATE,
+        runtime_args! {ARG_DELEGATEE => account_1},
+    )
+    .build();
+    builder.exec(delegate_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_votes(&mut builder, &cep18_token, account_1),
+        total_supply
+    );
+
+    // Tokens sent to an account without a delegate take their votes away.
+    let transfer_request =
+        make_cep18_transfer_request(owner, &cep18_token, account_2, transfer_amount);
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_votes(&mut builder, &cep18_token, account_1),
+        total_supply - transfer_amount
+    );
+
+    let delegate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_DELEGATE,
+        runtime_args! {ARG_DELEGATEE => account_2},
+    )
+    .build();
+    builder.exec(delegate_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_votes(&mut builder, &cep18_token, account_1),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_votes(&mut builder, &cep18_token, account_2),
+        total_supply - transfer_amount
+    );
+}
+
+#[test]
+fn should_not_get_past_votes_of_current_block_time() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let get_past_votes_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_GET_PAST_VOTES,
+        runtime_args! {
+            ARG_ACCOUNT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_TIMESTAMP => 1_000u64,
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(get_past_votes_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_FUTURE_LOOKUP),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_get_past_votes_between_checkpoints() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2 = Key::Account(*ACCOUNT_2_ADDR);
+    let total_supply = U256::from(TOKEN_TOTAL_SUPPLY);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+
+    let delegate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_DELEGATE,
+        runtime_args! {ARG_DELEGATEE => account_1},
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(delegate_request).expect_success().commit();
+
+    // One checkpoint per block, the two transfers of block time 3_000 sharing theirs.
+    for block_time in [2_000u64, 3_000, 3_000, 4_000] {
+        let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            METHOD_TRANSFER,
+            runtime_args! {ARG_RECIPIENT => account_2, ARG_AMOUNT => transfer_amount},
+        )
+        .with_block_time(block_time)
+        .build();
+        builder.exec(transfer_request).expect_success().commit();
+    }
+
+    let expected_votes = [
+        (999, U256::zero()),
+        (1_000, total_supply),
+        (1_999, total_supply),
+        (2_000, total_supply - transfer_amount),
+        (3_500, total_supply - transfer_amount * 3),
+        (4_000, total_supply - transfer_amount * 4),
+        (9_999, total_supply - transfer_amount * 4),
+    ];
+    for (timestamp, votes) in expected_votes {
+        assert_eq!(
+            cep18_check_past_votes(&mut builder, &cep18_token, account_1, timestamp, 10_000),
+            votes,
+            "votes at {}",
+            timestamp
+        );
+    }
+}
+
+#[test]
+fn should_not_record_delegation_to_current_delegatee() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        EVENTS_MODE => 1u8,
+    });
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+
+    let delegate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_DELEGATE,
+        runtime_args! {ARG_DELEGATEE => account_1},
+    )
+    .build();
+    builder.exec(delegate_request).expect_success().commit();
+    let events_length: u32 = builder.get_value(cep18_token, EVENTS_LENGTH_KEY);
+
+    let delegate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_DELEGATE,
+        runtime_args! {ARG_DELEGATEE => account_1},
+    )
+    .build();
+    builder.exec(delegate_request).expect_success().commit();
+
+    let new_events_length: u32 = builder.get_value(cep18_token, EVENTS_LENGTH_KEY);
+    assert_eq!(new_events_length, events_length);
+    assert_eq!(
+        cep18_check_votes(&mut builder, &cep18_token, account_1),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+}
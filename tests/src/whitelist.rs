@@ -0,0 +1,125 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ADDRESS, ARG_DECIMALS, ARG_NAME, ARG_SYMBOL,
+        ARG_TOTAL_SUPPLY, ERROR_RECIPIENT_NOT_WHITELISTED, KYC_ADMIN_LIST, METHOD_ADD_TO_WHITELIST,
+        METHOD_REMOVE_FROM_WHITELIST, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+        TRANSFER_AMOUNT_1, TRANSFER_AMOUNT_2, TRANSFER_FILTER_MODE,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, make_cep18_transfer_request, setup, setup_with_args, TestContext,
+    },
+};
+
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+
+#[test]
+fn should_only_credit_whitelisted_recipients_in_allowlist_mode() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        TRANSFER_FILTER_MODE => 1u8,
+        KYC_ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)]
+    });
+
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_1);
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_2_ADDR),
+        transfer_amount,
+    );
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECIPIENT_NOT_WHITELISTED),
+        "{:?}",
+        error
+    );
+
+    let whitelist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_ADD_TO_WHITELIST,
+        runtime_args! { ARG_ADDRESS => Key::Account(*ACCOUNT_2_ADDR) },
+    )
+    .build();
+    builder.exec(whitelist_request).expect_success().commit();
+
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_2_ADDR),
+        transfer_amount,
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        transfer_amount
+    );
+
+    // The installer is whitelisted from the start.
+    let transfer_back_request = make_cep18_transfer_request(
+        Key::Account(*ACCOUNT_2_ADDR),
+        &cep18_token,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        U256::from(TRANSFER_AMOUNT_2),
+    );
+    builder
+        .exec(transfer_back_request)
+        .expect_success()
+        .commit();
+
+    let unwhitelist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_REMOVE_FROM_WHITELIST,
+        runtime_args! { ARG_ADDRESS => Key::Account(*ACCOUNT_2_ADDR) },
+    )
+    .build();
+    builder.exec(unwhitelist_request).expect_success().commit();
+
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_2_ADDR),
+        transfer_amount,
+    );
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_RECIPIENT_NOT_WHITELISTED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_only_allow_kyc_admins_to_manage_whitelist() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let whitelist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_ADD_TO_WHITELIST,
+        runtime_args! { ARG_ADDRESS => Key::Account(*ACCOUNT_1_ADDR) },
+    )
+    .build();
+    builder.exec(whitelist_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == 60010),
+        "{:?}",
+        error
+    );
+}